@@ -0,0 +1,335 @@
+//! A variant of the rack storing values with a custom alignment.
+
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem;
+
+use crate::slot::{AsSlot, Slot};
+use crate::storage::{storage_methods, RackState, Storage};
+use crate::{AddUnitError, Iter, Rack, Unit};
+
+/// Implementation of [`Rack`](trait.Rack.html) trait holding up to `N` values
+/// of a type `T`, each of them aligned to at least `ALIGN` bytes.
+///
+/// This is useful for values which need a larger alignment than their type
+/// requires, e.g. vectors processed using SIMD instructions. The alignment
+/// must be a power of 2 up to 4096. Pointers obtained through
+/// [`Unit::as_ptr`](struct.Unit.html#method.as_ptr) satisfy it.
+///
+/// # Memory requirements
+///
+/// Each slot is padded to a multiple of the alignment and so is the rest of
+/// the rack. The formula for the memory requirements of the rack is
+/// following:
+///
+/// **`capacity_of_the_rack * round_up_to_the_closest_multiple_of_alignment(round_up_to_the_closest_multiple_of_4(size_of(value)) + 4) + round_up_to_the_closest_multiple_of_alignment(48)`**
+///
+/// # Examples
+///
+/// Store vectors aligned to 16 bytes:
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = AlignedRack::<[f32; 4], 16, 64>::new();
+///
+/// let vector = rack.must_add([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(vector.as_ptr() as usize % 16, 0);
+/// ```
+pub struct AlignedRack<T, const ALIGN: usize, const N: usize>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    slots: [AlignedSlot<T, ALIGN>; N],
+    state: RackState,
+}
+
+impl<T, const ALIGN: usize, const N: usize> AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    /// The number of bytes each slot takes on top of the value it holds,
+    /// including the padding up to the alignment of the rack.
+    ///
+    /// See [`ArrayRack::OVERHEAD_PER_SLOT`](struct.ArrayRack.html#associatedconstant.OVERHEAD_PER_SLOT).
+    pub const OVERHEAD_PER_SLOT: usize =
+        mem::size_of::<AlignedSlot<T, ALIGN>>() - mem::size_of::<T>();
+
+    /// Initialize a new Rack with a capacity of `N` and alignment of `ALIGN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = AlignedRack::<[f32; 4], 16, 64>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { AlignedSlot::new() }; N],
+            state: RackState::new(N),
+        }
+    }
+
+    /// Get the alignment of the stored values.
+    ///
+    /// It is the `ALIGN` of the rack, unless the type `T` requires even a
+    /// larger one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = AlignedRack::<u8, 16, 64>::new();
+    /// assert_eq!(rack.alignment(), 16);
+    ///
+    /// let rack = AlignedRack::<u64, 1, 64>::new();
+    /// assert_eq!(rack.alignment(), core::mem::align_of::<u64>());
+    /// ```
+    pub const fn alignment(&self) -> usize {
+        mem::align_of::<AlignedSlot<T, ALIGN>>()
+    }
+
+    storage_methods! {
+        rack: "let rack = AlignedRack::<_, 8, 4>::new();",
+        rack_mut: "let mut rack = AlignedRack::<_, 8, 4>::new();",
+    }
+
+    /// Create a new rack holding clones of the values owned by this one.
+    ///
+    /// See [`ArrayRack::clone_values`](struct.ArrayRack.html#method.clone_values).
+    pub fn clone_values(&self) -> Self
+    where
+        T: Clone,
+    {
+        let clone = Self::new();
+        self.storage().clone_into(clone.storage());
+        clone
+    }
+
+    fn storage(&self) -> Storage<'_, AlignedSlot<T, ALIGN>> {
+        self.state.set_host(self);
+        Storage {
+            slots: &self.slots,
+            state: &self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<T, const ALIGN: usize, const N: usize> Rack<T> for AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)> {
+        self.storage().add(value)
+    }
+
+    fn add_cloned(&self, value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        self.storage().add_cloned(value)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+/// Racks are compared slot by slot, the same way as
+/// [`ArrayRack`](struct.ArrayRack.html)s.
+impl<T: PartialEq, const ALIGN: usize, const N: usize> PartialEq for AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.iter_occupied().eq(other.iter_occupied())
+    }
+}
+
+impl<T: Eq, const ALIGN: usize, const N: usize> Eq for AlignedRack<T, ALIGN, N> where
+    Align<ALIGN>: SupportedAlign
+{
+}
+
+/// Racks are hashed slot by slot, the same way as
+/// [`ArrayRack`](struct.ArrayRack.html)s.
+impl<T: Hash, const ALIGN: usize, const N: usize> Hash for AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.storage().hash(state)
+    }
+}
+
+impl<'a, T, const ALIGN: usize, const N: usize> IntoIterator for &'a AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Cloning a rack creates a new empty one, the same way as cloning an
+/// [`ArrayRack`](struct.ArrayRack.html) does.
+impl<T, const ALIGN: usize, const N: usize> Clone for AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const ALIGN: usize, const N: usize> Default for AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An alignment which can be requested from an
+/// [`AlignedRack`](struct.AlignedRack.html).
+pub struct Align<const ALIGN: usize>;
+
+/// A trait implemented by all the alignments supported by
+/// [`AlignedRack`](struct.AlignedRack.html), i.e. powers of 2 up to 4096.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SupportedAlign: private::Sealed {
+    #[doc(hidden)]
+    type Archetype;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! align {
+    ($name:ident, $align:literal) => {
+        #[doc(hidden)]
+        #[repr(align($align))]
+        pub struct $name;
+
+        impl private::Sealed for Align<$align> {}
+
+        impl SupportedAlign for Align<$align> {
+            type Archetype = $name;
+        }
+    };
+}
+align!(Align1, 1);
+align!(Align2, 2);
+align!(Align4, 4);
+align!(Align8, 8);
+align!(Align16, 16);
+align!(Align32, 32);
+align!(Align64, 64);
+align!(Align128, 128);
+align!(Align256, 256);
+align!(Align512, 512);
+align!(Align1024, 1024);
+align!(Align2048, 2048);
+align!(Align4096, 4096);
+
+// A slot aligned to at least `ALIGN` bytes. The zero-sized array takes no
+// space, it only raises the alignment of the whole struct. Since the value is
+// placed at the very beginning of the slot, it shares the alignment.
+#[repr(C)]
+struct AlignedSlot<T, const ALIGN: usize>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    _align: [<Align<ALIGN> as SupportedAlign>::Archetype; 0],
+    slot: Slot<T>,
+}
+
+impl<T, const ALIGN: usize> AlignedSlot<T, ALIGN>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    const fn new() -> Self {
+        Self {
+            _align: [],
+            slot: Slot::new(),
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> AsSlot for AlignedSlot<T, ALIGN>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    type Value = T;
+
+    fn as_slot(&self) -> &Slot<T> {
+        &self.slot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_all_values_on_aligned_rack() {
+        let rack = AlignedRack::<[f32; 4], 16, 8>::new();
+
+        let units: [Unit<[f32; 4]>; 8] = core::array::from_fn(|i| rack.must_add([i as f32; 4]));
+
+        for unit in units.iter() {
+            assert_eq!(unit.as_ptr() as usize % 16, 0);
+        }
+    }
+
+    #[test]
+    fn align_values_to_page_size_on_aligned_rack() {
+        let rack = AlignedRack::<u8, 4096, 2>::new();
+
+        let unit1 = rack.must_add(1);
+        let unit2 = rack.must_add(2);
+
+        assert_eq!(unit1.as_ptr() as usize % 4096, 0);
+        assert_eq!(unit2.as_ptr() as usize % 4096, 0);
+        assert_eq!(
+            unit2.as_ptr() as usize - unit1.as_ptr() as usize,
+            rack.value_stride()
+        );
+        assert_eq!(rack.value_stride(), 4096);
+    }
+
+    #[test]
+    fn iterate_over_values_of_aligned_rack() {
+        let rack = AlignedRack::<u8, 64, 4>::new();
+
+        rack.must_insert(1);
+        let _unit = rack.must_add(2);
+        rack.must_insert(3);
+
+        let iter: Iter<u8> = rack.iter();
+        assert!(iter.eq(&[1, 3]));
+    }
+
+    #[test]
+    fn keep_alignment_of_type_over_lower_requested_alignment() {
+        let rack = AlignedRack::<u64, 2, 4>::new();
+
+        assert_eq!(rack.alignment(), mem::align_of::<u64>());
+    }
+
+    #[test]
+    fn measure_memory_overhead_of_aligned_rack() {
+        // Each slot of four bytes and four bytes of the tag rounds up to 16,
+        // and so does the rest of the rack.
+        let rack_size = mem::size_of::<AlignedRack<[u8; 4], 16, 4>>();
+
+        assert_eq!(rack_size, 4 * 16 + 48);
+        assert_eq!(AlignedRack::<[u8; 4], 16, 4>::OVERHEAD_PER_SLOT, 12);
+    }
+}
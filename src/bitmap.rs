@@ -0,0 +1,124 @@
+//! Tracking of occupied slots, allowing a free one to be found without
+//! scanning the whole rack.
+
+use core::cell::Cell;
+use core::cmp;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+// Number of groups the bitmap is able to track.
+const BITS: usize = u64::BITS as usize;
+
+// A bitmap summarizing occupancy of the slots of a rack. Slots are split into
+// up to 64 groups of the same size, each of them represented by a single bit
+// which is set once all the slots of the group are occupied. Racks of up to 64
+// slots therefore get a bit per slot, larger racks only need to look through a
+// single group to find a free slot.
+//
+// It is used instead of a free list threaded through empty slots. Although
+// the list would hand out slots in constant time, it would require every slot
+// to be large enough to hold an index and reuse slots in last-in first-out
+// order, while the bitmap keeps the lowest free index first.
+//
+// Besides that, it keeps a cursor to the first slot which may be free, so
+// filling the rack in order does not scan the occupied start of a group over
+// and over again. It also remembers the slot released most recently, so it can
+// be handed out again while it is likely still cached.
+pub(crate) struct Bitmap {
+    full: Cell<u64>,
+    // Number of slots covered by a single bit. This and the indexes below are
+    // kept in 32 bits to keep the bitmap small.
+    group: u32,
+    // All the slots before this index are occupied. It may lag behind, but it
+    // never skips a free slot.
+    cursor: Cell<u32>,
+    // `NONE` unless a slot was released. Slots with an index not fitting into
+    // 32 bits are never remembered.
+    last_freed: Cell<u32>,
+}
+
+const NONE: u32 = u32::MAX;
+
+impl Bitmap {
+    pub(crate) const fn new(capacity: usize) -> Self {
+        Self {
+            full: Cell::new(0),
+            group: if capacity <= BITS {
+                1
+            } else {
+                capacity.div_ceil(BITS) as u32
+            },
+            cursor: Cell::new(0),
+            last_freed: Cell::new(NONE),
+        }
+    }
+
+    // Get indexes of slots belonging to the first group which has a free slot.
+    pub(crate) fn first_free_group(&self, capacity: usize) -> Option<Range<usize>> {
+        // Trailing zeros of a fully set bitmap are counted as 64, which is
+        // always past the last group.
+        let start = (!self.full.get()).trailing_zeros() as usize * self.group();
+        if start < capacity {
+            Some(self.group_of(start, capacity))
+        } else {
+            None
+        }
+    }
+
+    // Get indexes of slots belonging to the same group as the given one.
+    pub(crate) fn group_of(&self, index: usize, capacity: usize) -> Range<usize> {
+        let start = index - index % self.group();
+        start..cmp::min(start + self.group(), capacity)
+    }
+
+    // Mark the group of the given slot as fully occupied.
+    pub(crate) fn set_full(&self, index: usize) {
+        self.full.set(self.full.get() | self.mask(index));
+    }
+
+    // Get the index of the first slot which may be free.
+    pub(crate) fn cursor(&self) -> usize {
+        self.cursor.get() as usize
+    }
+
+    // Remember that all the slots before the given one are occupied. Indexes
+    // not fitting into 32 bits are ignored, keeping the cursor behind.
+    pub(crate) fn skip_to(&self, index: usize) {
+        if let Ok(index) = u32::try_from(index) {
+            self.cursor.set(cmp::max(self.cursor.get(), index));
+        }
+    }
+
+    // Mark the group of the given slot as having a free slot, move the cursor
+    // back to it and remember it as the slot released most recently.
+    pub(crate) fn release(&self, index: usize) {
+        self.full.set(self.full.get() & !self.mask(index));
+        if index < self.cursor() {
+            self.cursor.set(index as u32);
+        }
+        self.last_freed
+            .set(u32::try_from(index).map_or(NONE, |index| index));
+    }
+
+    // Get the index of the slot released most recently. It may have been
+    // claimed again since then.
+    pub(crate) fn last_freed(&self) -> Option<usize> {
+        match self.last_freed.get() {
+            NONE => None,
+            index => Some(index as usize),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_full(&self, index: usize) -> bool {
+        self.full.get() & self.mask(index) != 0
+    }
+
+    fn group(&self) -> usize {
+        self.group as usize
+    }
+
+    fn mask(&self, index: usize) -> u64 {
+        1 << (index / self.group())
+    }
+}
@@ -0,0 +1,92 @@
+//! Byte views of values held by units using
+//! [zerocopy](https://docs.rs/zerocopy), enabled by the `zerocopy` feature.
+
+use zerocopy::{AsBytes, FromBytes};
+
+use crate::Unit;
+
+impl<T: AsBytes> Unit<'_, T> {
+    /// View the value held by the unit as bytes, without copying it.
+    ///
+    /// The bytes stay at the same address for as long as the unit lives, so
+    /// they can be handed over to DMA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let unit = rack.must_add(0x0102_u16);
+    /// assert_eq!(unit.as_bytes(), 0x0102_u16.to_ne_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.get_ref().as_bytes()
+    }
+
+    /// View the value held by the unit as mutable bytes, without copying it.
+    ///
+    /// The value must accept any bytes written into it, which is why it has to
+    /// implement `FromBytes` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let mut unit = rack.must_add(0_u16);
+    /// unit.as_bytes_mut().copy_from_slice(&0x0102_u16.to_ne_bytes());
+    /// assert_eq!(*unit, 0x0102);
+    /// ```
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: FromBytes,
+    {
+        self.get_mut().as_bytes_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+    use crate::{Rack, Rack64};
+
+    #[derive(AsBytes, FromZeroes, FromBytes)]
+    #[repr(C)]
+    struct Header {
+        length: u32,
+        flags: u16,
+        checksum: u16,
+    }
+
+    #[test]
+    fn view_stored_struct_as_bytes() {
+        let rack = Rack64::new();
+        let unit = rack.must_add(Header {
+            length: 1,
+            flags: 2,
+            checksum: 3,
+        });
+
+        let bytes = unit.as_bytes();
+        assert_eq!(bytes.len(), mem::size_of::<Header>());
+        assert_eq!(bytes.as_ptr(), unit.get_ref() as *const Header as *const u8);
+    }
+
+    #[test]
+    fn overwrite_stored_struct_through_its_bytes() {
+        let rack = Rack64::new();
+        let mut unit = rack.must_add(Header {
+            length: 1,
+            flags: 2,
+            checksum: 3,
+        });
+
+        unit.as_bytes_mut().fill(0);
+        assert_eq!(unit.length, 0);
+        assert_eq!(unit.flags, 0);
+        assert_eq!(unit.checksum, 0);
+    }
+}
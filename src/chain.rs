@@ -0,0 +1,147 @@
+//! A rack spilling values into a fallback rack once the primary one is full.
+
+use core::mem;
+
+use crate::{AddUnitError, Rack, Unit};
+
+/// Implementation of [`Rack`](trait.Rack.html) trait chaining two other
+/// racks.
+///
+/// Values are added to the primary rack until it is full, then they spill
+/// into the fallback one. An error is returned only when both of them are
+/// full. Units borrow the chain, no matter which of the racks they landed in.
+///
+/// Chains can be nested to spill over more than two racks.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let primary = Rack2::new();
+/// let fallback = Rack4::new();
+/// let rack = ChainRack::new(&primary, &fallback);
+///
+/// let units: Vec<_> = (0..6).map(|i| rack.must_add(i)).collect();
+///
+/// assert_eq!(rack.capacity(), 6);
+/// assert!(rack.add(6).is_err());
+/// ```
+pub struct ChainRack<'a, A: ?Sized, B: ?Sized> {
+    primary: &'a A,
+    fallback: &'a B,
+}
+
+impl<'a, A: ?Sized, B: ?Sized> ChainRack<'a, A, B> {
+    /// Chain the primary rack with a fallback one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let primary = Rack64::<i32>::new();
+    /// let fallback = Rack1024::<i32>::new();
+    /// let rack = ChainRack::new(&primary, &fallback);
+    /// ```
+    pub const fn new(primary: &'a A, fallback: &'a B) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> ChainRack<'_, A, B> {
+    // Report the capacity of both of the racks once they are full, rather than
+    // the one of the fallback.
+    fn full<T>(&self, error: AddUnitError) -> AddUnitError
+    where
+        Self: Rack<T>,
+    {
+        match error {
+            AddUnitError::FullRack { .. } => AddUnitError::FullRack {
+                capacity: self.capacity(),
+            },
+            error => error,
+        }
+    }
+}
+
+impl<T, A: Rack<T> + ?Sized, B: Rack<T> + ?Sized> Rack<T> for ChainRack<'_, A, B> {
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)> {
+        match self.primary.add_or_return(value) {
+            Ok(unit) => Ok(unit),
+            Err((value, _)) => self
+                .fallback
+                .add_or_return(value)
+                .map_err(|(value, error)| (value, self.full(error))),
+        }
+    }
+
+    fn add_cloned(&self, value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        self.primary
+            .add_cloned(value)
+            .or_else(|_| self.fallback.add_cloned(value))
+            .map_err(|error| self.full(error))
+    }
+
+    fn capacity(&self) -> usize {
+        self.primary.capacity() + self.fallback.capacity()
+    }
+
+    /// Both of the chained racks are counted in as well.
+    fn memory_bytes(&self) -> usize {
+        mem::size_of_val(self) + self.primary.memory_bytes() + self.fallback.memory_bytes()
+    }
+
+    fn value_bytes(&self) -> usize {
+        self.primary.value_bytes() + self.fallback.value_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rack2, Rack4};
+
+    #[test]
+    fn spill_values_into_fallback_rack() {
+        let primary = Rack2::new();
+        let fallback = Rack4::new();
+        let rack = ChainRack::new(&primary, &fallback);
+
+        let units: [Unit<i32>; 5] = core::array::from_fn(|i| rack.must_add(i as i32));
+
+        assert!(units.iter().map(|unit| **unit).eq(0..5));
+        assert!(primary.add(5).is_err());
+        assert!(fallback.add(5).is_ok());
+    }
+
+    #[test]
+    fn fail_when_all_chained_racks_are_full() {
+        let primary = Rack2::new();
+        let fallback = Rack2::new();
+        let rack = ChainRack::new(&primary, &fallback);
+
+        let _units: [Unit<i32>; 4] = core::array::from_fn(|i| rack.must_add(i as i32));
+
+        let error = AddUnitError::FullRack { capacity: 4 };
+        assert_eq!(rack.add(4).unwrap_err(), error);
+        assert_eq!(rack.add_cloned(&4).unwrap_err(), error);
+    }
+
+    #[test]
+    fn clone_unit_into_chained_rack_it_was_allocated_from() {
+        let primary = Rack2::new();
+        let fallback = Rack2::new();
+        let rack = ChainRack::new(&primary, &fallback);
+
+        let first = rack.must_add(1);
+        let _second = first.try_clone().unwrap();
+
+        assert_eq!(
+            first.try_clone().unwrap_err(),
+            AddUnitError::FullRack { capacity: 2 }
+        );
+        assert!(fallback.add(3).is_ok());
+    }
+}
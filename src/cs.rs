@@ -0,0 +1,178 @@
+//! An interrupt-safe variant of the rack, guarding its slots by critical
+//! sections, enabled by the `critical-section` feature.
+
+use core::cell::Cell;
+
+use critical_section::Mutex;
+
+use crate::sync::{private, SlotFlag};
+use crate::{SyncRack, SyncUnit};
+
+/// An interrupt-safe rack holding up to `N` values of a type `T`.
+///
+/// Claiming and releasing of slots happens inside of a critical section
+/// provided by the [critical-section](https://docs.rs/critical-section)
+/// crate, so values can be added and dropped both from the main context and
+/// from interrupt handlers, even on targets without atomic instructions.
+/// Only the lookup of a free slot runs in a critical section, the value is
+/// moved to the slot after it ends. Values added to the rack are owned by
+/// [`CsUnit`](type.CsUnit.html).
+///
+/// It is a [`SyncRack`](struct.SyncRack.html) tracking occupancy of its slots
+/// by flags guarded by critical sections instead of atomic ones. Since it
+/// returns a different type of units, `CsRack` does not implement the
+/// [`Rack`](trait.Rack.html) trait.
+///
+/// # Examples
+///
+/// Keep a pool of values in a `static` item shared with an interrupt handler:
+///
+/// ```
+/// # use heapnotize::*;
+/// static RACK: CsRack<i32, 64> = CsRack::new();
+///
+/// let five = RACK.must_add(5);
+/// assert_eq!(*five, 5);
+/// ```
+pub type CsRack<T, const N: usize> = SyncRack<T, N, Mutex<Cell<bool>>>;
+
+/// A type serving as an owner of a value stored on the
+/// [`CsRack`](type.CsRack.html).
+///
+/// It works the same way as [`Unit`](struct.Unit.html). Once the `CsUnit` gets
+/// out of the scope, the value that it holds gets dropped and its slot is
+/// released inside of a critical section.
+pub type CsUnit<'a, T> = SyncUnit<'a, T, Mutex<Cell<bool>>>;
+
+impl private::Sealed for Mutex<Cell<bool>> {}
+
+// All the flags are looked through inside of a single critical section, so a
+// slot cannot be claimed by an interrupt handler between finding it free and
+// raising its flag.
+impl SlotFlag for Mutex<Cell<bool>> {
+    const FREE: Self = Mutex::new(Cell::new(false));
+    const UNIT_NAME: &'static str = "CsUnit";
+
+    fn claim_first(flags: &[Self]) -> Option<usize> {
+        critical_section::with(|cs| {
+            let index = flags.iter().position(|flag| !flag.borrow(cs).get())?;
+            flags[index].borrow(cs).set(true);
+            Some(index)
+        })
+    }
+
+    fn release(&self) {
+        critical_section::with(|cs| self.borrow(cs).set(false));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::format;
+
+    use super::*;
+
+    #[test]
+    fn rejects_over_the_limit_of_cs_rack() {
+        let rack = CsRack::<_, 2>::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+
+        assert!(rack.add(30).is_err());
+    }
+
+    #[test]
+    fn add_and_drop_cs_units_inside_of_critical_section() {
+        let rack = CsRack::<_, 2>::new();
+
+        // The critical section of the std implementation can be nested, the
+        // same way as the one of an interrupt handler preempting the main
+        // context while it holds a unit.
+        let unit = critical_section::with(|_| {
+            let unit1 = rack.must_add(1);
+            let unit2 = rack.must_add(2);
+            assert!(rack.add(3).is_err());
+            drop(unit1);
+            assert_eq!(*unit2, 2);
+            rack.must_add(3)
+        });
+
+        assert_eq!(unit.index(), 0);
+        assert_eq!(*unit, 3);
+    }
+
+    #[test]
+    fn share_cs_rack_with_simulated_interrupt_handler() {
+        static RACK: CsRack<u32, 2> = CsRack::new();
+
+        fn interrupt_handler() {
+            let unit = RACK.must_add(2);
+            assert_eq!(unit.index(), 1);
+        }
+
+        let unit = RACK.must_add(1);
+        interrupt_handler();
+        interrupt_handler();
+
+        assert_eq!(*unit, 1);
+        assert_eq!(RACK.must_add(3).index(), 1);
+    }
+
+    #[test]
+    fn release_cs_slot_even_if_value_panics_on_drop() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        struct PanicOnDrop;
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                panic!("PanicOnDrop dropped");
+            }
+        }
+
+        let rack = CsRack::<_, 1>::new();
+
+        let unit = rack.must_add(Some(PanicOnDrop));
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(unit))).is_err());
+
+        let unit = rack.must_add(None);
+        assert_eq!(unit.index(), 0);
+    }
+
+    #[test]
+    fn format_cs_unit_with_its_own_name() {
+        let rack = CsRack::<_, 1>::new();
+
+        let unit = rack.must_add(5);
+
+        assert_eq!(format!("{:?}", unit), "CsUnit { value: 5, index: 0 }");
+    }
+
+    #[test]
+    fn keep_occupancy_of_cs_rack_consistent_between_threads() {
+        const THREADS: usize = 4;
+        const ITERATIONS: usize = 1000;
+
+        let rack = CsRack::<_, THREADS>::new();
+
+        std::thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let rack = &rack;
+                scope.spawn(move || {
+                    for i in 0..ITERATIONS {
+                        let value = thread * ITERATIONS + i;
+                        let unit = rack.must_add(value);
+                        assert_eq!(*unit, value);
+                    }
+                });
+            }
+        });
+
+        // All the slots must have been released by the threads.
+        let _units: [CsUnit<_>; THREADS] = core::array::from_fn(|i| rack.must_add(i));
+        assert!(rack.add(THREADS).is_err());
+    }
+}
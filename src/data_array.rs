@@ -1,2110 +0,0 @@
-//! This generate library serves for initalization of [`Rack`](../trait.Rack.html)
-//! instances.
-//!
-//! This is not the most elegant solution, nor the most flexible one. Once
-//! [Const Generics](https://github.com/rust-lang/rust/issues/49147) are
-//! implemented, they will be used to implement `Rack` generic type with
-//! arbitrary length. Until then, this is the way to go.
-
-use core::cell::RefCell;
-use core::mem::MaybeUninit;
-
-pub fn init_1<T>() -> [RefCell<MaybeUninit<T>>; 1] {
-    [RefCell::new(MaybeUninit::uninit())]
-}
-
-pub fn init_2<T>() -> [RefCell<MaybeUninit<T>>; 2] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_4<T>() -> [RefCell<MaybeUninit<T>>; 4] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_8<T>() -> [RefCell<MaybeUninit<T>>; 8] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_16<T>() -> [RefCell<MaybeUninit<T>>; 16] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_32<T>() -> [RefCell<MaybeUninit<T>>; 32] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_64<T>() -> [RefCell<MaybeUninit<T>>; 64] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_128<T>() -> [RefCell<MaybeUninit<T>>; 128] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_256<T>() -> [RefCell<MaybeUninit<T>>; 256] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_512<T>() -> [RefCell<MaybeUninit<T>>; 512] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
-
-pub fn init_1024<T>() -> [RefCell<MaybeUninit<T>>; 1024] {
-    [
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-        RefCell::new(MaybeUninit::uninit()),
-    ]
-}
@@ -0,0 +1,714 @@
+//! A variant of the rack storing values of different types behind a trait
+//! object, or byte slices and strings of different lengths.
+
+use core::any::Any;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+use core::{slice, str};
+
+use crate::slot::Slot;
+use crate::storage::{RackState, Storage};
+use crate::{AddUnitError, Unit};
+
+/// A rack holding up to `N` values of any types implementing a common trait,
+/// each of them taking up to `SLOT` bytes.
+///
+/// The trait object type is given as `Dyn`, e.g. `dyn Draw`. Values are kept
+/// inline in the slots of the rack, aligned to 16 bytes at most. The returned
+/// [`DynUnit`](struct.DynUnit.html) dereferences to the trait object.
+///
+/// Since stable Rust cannot convert a generic value to a trait object on its
+/// own, the conversion is given to [`add`](#method.add) by the caller. It is
+/// usually an identity closure, `|value| value`.
+///
+/// # Memory requirements
+///
+/// Each slot takes `SLOT` bytes rounded up to the closest multiple of 16,
+/// plus another 16 bytes keeping its state.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// trait Shape {
+///     fn area(&self) -> u32;
+/// }
+///
+/// struct Square(u32);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> u32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// struct Rectangle(u32, u32);
+///
+/// impl Shape for Rectangle {
+///     fn area(&self) -> u32 {
+///         self.0 * self.1
+///     }
+/// }
+///
+/// let rack = DynRack::<dyn Shape, 8, 64>::new();
+///
+/// let square = rack.must_add(Square(2), |value| value);
+/// let rectangle = rack.must_add(Rectangle(2, 3), |value| value);
+///
+/// assert_eq!(square.area() + rectangle.area(), 10);
+/// ```
+pub struct DynRack<Dyn: ?Sized, const SLOT: usize, const N: usize> {
+    slots: [Slot<Buffer<SLOT>>; N],
+    state: RackState,
+    _marker: PhantomData<Dyn>,
+}
+
+// Inline storage of a value of any type fitting into `SLOT` bytes.
+#[repr(C, align(16))]
+struct Buffer<const SLOT: usize>([MaybeUninit<u8>; SLOT]);
+
+// A value of type `U` stored in a slot, before it is converted to the trait
+// object. It is dropped together with the unit releasing the slot in case the
+// conversion fails.
+struct Typed<'a, U, const SLOT: usize> {
+    unit: Unit<'a, Buffer<SLOT>>,
+    value: *mut U,
+}
+
+impl<U, const SLOT: usize> Drop for Typed<'_, U, SLOT> {
+    fn drop(&mut self) {
+        // This is safe since the value was written to the slot and it is not
+        // accessed anymore. The unit releases the slot even if the destructor
+        // panics.
+        unsafe {
+            ptr::drop_in_place(self.value);
+        }
+    }
+}
+
+impl<Dyn: ?Sized, const SLOT: usize, const N: usize> DynRack<Dyn, SLOT, N> {
+    /// Initialize a new Rack with a capacity of `N` and slots of `SLOT` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = DynRack::<dyn core::fmt::Debug, 16, 64>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { Slot::new() }; N],
+            state: RackState::new(N),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add a value to the `Rack` and return a `DynUnit` owning it as a trait
+    /// object.
+    ///
+    /// The `coerce` function converts a reference to the value into a
+    /// reference to the trait object. It is usually `|value| value`.
+    ///
+    /// # Errors
+    ///
+    /// In case the `Rack` is full, [`AddUnitError::FullRack`](enum.AddUnitError.html)
+    /// is returned. In case the value is larger than `SLOT` bytes or aligned
+    /// to more than 16 bytes,
+    /// [`AddUnitError::OversizedValue`](enum.AddUnitError.html) is returned.
+    /// The value is dropped in both cases.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case `coerce` returns a reference to
+    /// anything else than the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::fmt::Debug;
+    ///
+    /// let rack = DynRack::<dyn Debug, 4, 64>::new();
+    ///
+    /// let five = rack.add(5_u32, |value| value).unwrap();
+    /// assert_eq!(format!("{:?}", five), "5");
+    ///
+    /// assert_eq!(
+    ///     rack.add(5_u64, |value| value).unwrap_err(),
+    ///     AddUnitError::OversizedValue
+    /// );
+    /// ```
+    pub fn add<U, F>(&self, value: U, coerce: F) -> Result<DynUnit<'_, Dyn>, AddUnitError>
+    where
+        F: FnOnce(&mut U) -> &mut Dyn,
+    {
+        if mem::size_of::<U>() > SLOT || mem::align_of::<U>() > mem::align_of::<Buffer<SLOT>>() {
+            return Err(AddUnitError::OversizedValue);
+        }
+        let buffer = Buffer([MaybeUninit::uninit(); SLOT]);
+        let unit = self.storage().add(buffer).map_err(|(_, error)| error)?;
+        let slot = unit.slot.as_ptr().cast::<U>();
+        // This is safe since the slot is claimed by the unit, it is large
+        // enough to hold the value and aligned for it.
+        unsafe {
+            slot.write(value);
+        }
+        let typed = Typed { unit, value: slot };
+        // This is safe since the value was just written to the slot.
+        let coerced = NonNull::from(coerce(unsafe { &mut *slot }));
+        assert!(
+            coerced.as_ptr().cast::<U>() == slot,
+            "The coercion must return the added value"
+        );
+        let typed = ManuallyDrop::new(typed);
+        Ok(DynUnit {
+            value: coerced,
+            slot: typed.unit.slot,
+            index: typed.unit.index,
+            state: typed.unit.state,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Add a value to the `Rack` and return a `DynUnit` owning it as a trait
+    /// object. See [`add`](#method.add) for details.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is full or the value does not
+    /// fit into its slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = DynRack::<dyn core::fmt::Display, 8, 64>::new();
+    /// let five = rack.must_add(5, |value| value);
+    /// assert_eq!(five.to_string(), "5");
+    /// ```
+    pub fn must_add<U, F>(&self, value: U, coerce: F) -> DynUnit<'_, Dyn>
+    where
+        F: FnOnce(&mut U) -> &mut Dyn,
+    {
+        match self.add(value, coerce) {
+            Ok(unit) => unit,
+            Err(AddUnitError::OversizedValue) => panic!("The value does not fit into the rack"),
+            Err(_) => panic!("The rack is full"),
+        }
+    }
+
+    /// Get the maximum number of values the rack can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = DynRack::<dyn core::fmt::Debug, 16, 64>::new();
+    /// assert_eq!(rack.capacity(), 64);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    fn storage(&self) -> Storage<'_, Slot<Buffer<SLOT>>> {
+        Storage {
+            slots: &self.slots,
+            state: &self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+/// A rack holding up to `N` byte slices or strings, each of them taking up to
+/// `SLOT` bytes.
+///
+/// The bytes are copied inline into the slots of the rack. The returned
+/// [`SliceUnit`](type.SliceUnit.html) dereferences to `[u8]` or `str` and
+/// keeps the length of the stored data, so no space of the slot is spent on
+/// it. This is a [`DynRack`](struct.DynRack.html) of `[u8]`, see its memory
+/// requirements.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = InlineRack::<16, 64>::new();
+///
+/// let greeting = rack.must_add_str("hello");
+/// let payload = rack.must_add_slice(&[1, 2, 3]);
+///
+/// assert_eq!(&*greeting, "hello");
+/// assert_eq!(&*payload, [1, 2, 3]);
+/// ```
+pub type InlineRack<const SLOT: usize, const N: usize> = DynRack<[u8], SLOT, N>;
+
+/// A type serving as an owner of a byte slice or a string stored on the
+/// [`InlineRack`](type.InlineRack.html).
+pub type SliceUnit<'a, S = [u8]> = DynUnit<'a, S>;
+
+impl<const SLOT: usize, const N: usize> DynRack<[u8], SLOT, N> {
+    /// Copy the bytes to the `Rack` and return a `SliceUnit` owning them.
+    ///
+    /// # Errors
+    ///
+    /// In case the `Rack` is full, [`AddUnitError::FullRack`](enum.AddUnitError.html)
+    /// is returned. In case there are more than `SLOT` bytes,
+    /// [`AddUnitError::OversizedValue`](enum.AddUnitError.html) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = InlineRack::<4, 64>::new();
+    ///
+    /// let mut bytes = rack.add_slice(&[1, 2]).unwrap();
+    /// bytes[0] = 3;
+    /// assert_eq!(&*bytes, [3, 2]);
+    ///
+    /// assert_eq!(
+    ///     rack.add_slice(&[1, 2, 3, 4, 5]).unwrap_err(),
+    ///     AddUnitError::OversizedValue
+    /// );
+    /// ```
+    pub fn add_slice(&self, bytes: &[u8]) -> Result<SliceUnit<'_>, AddUnitError> {
+        if bytes.len() > SLOT {
+            return Err(AddUnitError::OversizedValue);
+        }
+        let buffer = Buffer([MaybeUninit::uninit(); SLOT]);
+        let unit = self.storage().add(buffer).map_err(|(_, error)| error)?;
+        let data = unit.slot.as_ptr().cast::<u8>();
+        // This is safe since the slot is claimed by the unit and it is large
+        // enough to hold the bytes. The initialized bytes are then viewed as a
+        // slice.
+        let value = unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), data, bytes.len());
+            NonNull::from(slice::from_raw_parts_mut(data, bytes.len()))
+        };
+        Ok(DynUnit::from_unit(unit, value))
+    }
+
+    /// Copy the bytes to the `Rack` and return a `SliceUnit` owning them.
+    /// See [`add_slice`](#method.add_slice) for details.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is full or the bytes do not
+    /// fit into its slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = InlineRack::<4, 64>::new();
+    /// let bytes = rack.must_add_slice(&[1, 2]);
+    /// ```
+    pub fn must_add_slice(&self, bytes: &[u8]) -> SliceUnit<'_> {
+        match self.add_slice(bytes) {
+            Ok(unit) => unit,
+            Err(AddUnitError::OversizedValue) => panic!("The value does not fit into the rack"),
+            Err(_) => panic!("The rack is full"),
+        }
+    }
+
+    /// Copy the string to the `Rack` and return a `SliceUnit` owning it.
+    ///
+    /// # Errors
+    ///
+    /// In case the `Rack` is full, [`AddUnitError::FullRack`](enum.AddUnitError.html)
+    /// is returned. In case the string takes more than `SLOT` bytes,
+    /// [`AddUnitError::OversizedValue`](enum.AddUnitError.html) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = InlineRack::<8, 64>::new();
+    ///
+    /// let mut name = rack.add_str("rack").unwrap();
+    /// name.make_ascii_uppercase();
+    /// assert_eq!(&*name, "RACK");
+    /// ```
+    pub fn add_str(&self, string: &str) -> Result<SliceUnit<'_, str>, AddUnitError> {
+        let unit = ManuallyDrop::new(self.add_slice(string.as_bytes())?);
+        // This is safe since the bytes were copied from a valid string.
+        let value =
+            unsafe { NonNull::from(str::from_utf8_unchecked_mut(&mut *unit.value.as_ptr())) };
+        Ok(DynUnit {
+            value,
+            slot: unit.slot,
+            index: unit.index,
+            state: unit.state,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Copy the string to the `Rack` and return a `SliceUnit` owning it. See
+    /// [`add_str`](#method.add_str) for details.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is full or the string does
+    /// not fit into its slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = InlineRack::<8, 64>::new();
+    /// let name = rack.must_add_str("rack");
+    /// ```
+    pub fn must_add_str(&self, string: &str) -> SliceUnit<'_, str> {
+        match self.add_str(string) {
+            Ok(unit) => unit,
+            Err(AddUnitError::OversizedValue) => panic!("The value does not fit into the rack"),
+            Err(_) => panic!("The rack is full"),
+        }
+    }
+}
+
+impl<Dyn: ?Sized, const SLOT: usize, const N: usize> Default for DynRack<Dyn, SLOT, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A type serving as an owner of a value stored on the
+/// [`DynRack`](struct.DynRack.html), accessible as a trait object `Dyn`.
+///
+/// Once the `DynUnit` gets out of the scope, the value that it holds gets
+/// dropped.
+pub struct DynUnit<'a, Dyn: ?Sized> {
+    // Points into the slot, carrying the metadata of the trait object.
+    value: NonNull<Dyn>,
+    // The size of the slot is erased, so units of racks with different slots
+    // share the same type.
+    slot: &'a dyn Release,
+    index: usize,
+    state: &'a RackState,
+    _marker: PhantomData<&'a mut Dyn>,
+}
+
+// Release of a slot holding a value of any type.
+trait Release {
+    fn release(&self);
+}
+
+impl<T> Release for Slot<T> {
+    fn release(&self) {
+        Slot::release(self);
+    }
+}
+
+impl<'a, Dyn: ?Sized> DynUnit<'a, Dyn> {
+    // Take over the slot of the unit, accessing the value stored in it through
+    // the given pointer.
+    fn from_unit<const SLOT: usize>(unit: Unit<'a, Buffer<SLOT>>, value: NonNull<Dyn>) -> Self {
+        let unit = ManuallyDrop::new(unit);
+        DynUnit {
+            value,
+            slot: unit.slot,
+            index: unit.index,
+            state: unit.state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::fmt::Debug;
+    ///
+    /// let rack = DynRack::<dyn Debug, 8, 64>::new();
+    /// let unit = rack.must_add(5, |value| value);
+    ///
+    /// assert_eq!(format!("{:?}", unit.get_ref()), "5");
+    /// ```
+    pub fn get_ref(&self) -> &Dyn {
+        // This is safe since the unit is the only owner of the value.
+        unsafe { self.value.as_ref() }
+    }
+
+    /// Get a mutable reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::fmt::Write;
+    ///
+    /// struct Counter(usize);
+    ///
+    /// impl Write for Counter {
+    ///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    ///         self.0 += s.len();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let rack = DynRack::<dyn Write, 8, 64>::new();
+    /// let mut unit = rack.must_add(Counter(0), |value| value);
+    ///
+    /// unit.get_mut().write_str("hello").unwrap();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut Dyn {
+        // This is safe since the unit is the only owner of the value and it is
+        // borrowed mutably.
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl DynUnit<'_, dyn Any> {
+    /// Get a reference to the stored value if it is of the type `T`.
+    ///
+    /// This makes a rack of `dyn Any` a type-erased registry of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::any::Any;
+    ///
+    /// let rack = DynRack::<dyn Any, 8, 64>::new();
+    /// let five = rack.must_add(5_i32, |value| value);
+    ///
+    /// assert_eq!(five.downcast_ref::<i32>(), Some(&5));
+    /// assert_eq!(five.downcast_ref::<u32>(), None);
+    /// ```
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.get_ref().downcast_ref()
+    }
+
+    /// Get a mutable reference to the stored value if it is of the type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::any::Any;
+    ///
+    /// let rack = DynRack::<dyn Any, 8, 64>::new();
+    /// let mut number = rack.must_add(5_i32, |value| value);
+    ///
+    /// *number.downcast_mut::<i32>().unwrap() = 10;
+    /// assert_eq!(number.downcast_ref::<i32>(), Some(&10));
+    /// ```
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.get_mut().downcast_mut()
+    }
+}
+
+impl<Dyn: ?Sized> Drop for DynUnit<'_, Dyn> {
+    fn drop(&mut self) {
+        // The slot is released even if the destructor of the value panics,
+        // same as with a `Unit`.
+        struct Release<'u, 'a, Dyn: ?Sized>(&'u DynUnit<'a, Dyn>);
+
+        impl<Dyn: ?Sized> Drop for Release<'_, '_, Dyn> {
+            fn drop(&mut self) {
+                self.0.slot.release();
+                self.0.state.release(self.0.index);
+            }
+        }
+
+        let _release = Release(self);
+        // This is safe since the DynUnit was the only owner of the stored data.
+        // The value is not accessed again, even if its destructor panics.
+        unsafe {
+            ptr::drop_in_place(self.value.as_ptr());
+        }
+    }
+}
+
+impl<Dyn: ?Sized> Deref for DynUnit<'_, Dyn> {
+    type Target = Dyn;
+
+    fn deref(&self) -> &Self::Target {
+        self.get_ref()
+    }
+}
+
+impl<Dyn: ?Sized> DerefMut for DynUnit<'_, Dyn> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.get_mut()
+    }
+}
+
+impl<Dyn: ?Sized + fmt::Debug> fmt::Debug for DynUnit<'_, Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.get_ref().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    use std::rc::Rc;
+
+    trait Draw {
+        fn draw(&self) -> u32;
+    }
+
+    struct Point(u8);
+
+    impl Draw for Point {
+        fn draw(&self) -> u32 {
+            u32::from(self.0)
+        }
+    }
+
+    struct Line(u32, u32);
+
+    impl Draw for Line {
+        fn draw(&self) -> u32 {
+            self.0 + self.1
+        }
+    }
+
+    #[test]
+    fn call_trait_methods_of_different_types_through_dyn_units() {
+        let rack = DynRack::<dyn Draw, 8, 4>::new();
+
+        let point = rack.must_add(Point(1), |value| value);
+        let line = rack.must_add(Line(2, 3), |value| value);
+
+        assert_eq!(point.draw(), 1);
+        assert_eq!(line.draw(), 5);
+    }
+
+    #[test]
+    fn reject_values_not_fitting_into_dyn_rack_slots() {
+        #[repr(align(32))]
+        struct Aligned;
+
+        impl Draw for Aligned {
+            fn draw(&self) -> u32 {
+                0
+            }
+        }
+
+        let small = DynRack::<dyn Draw, 4, 4>::new();
+        let large = DynRack::<dyn Draw, 64, 4>::new();
+
+        assert_eq!(
+            small.add(Line(1, 2), |value| value).err(),
+            Some(AddUnitError::OversizedValue)
+        );
+        assert_eq!(
+            large.add(Aligned, |value| value).err(),
+            Some(AddUnitError::OversizedValue)
+        );
+        assert!(small.add(Point(1), |value| value).is_ok());
+    }
+
+    #[test]
+    fn reject_values_over_dyn_rack_capacity() {
+        let rack = DynRack::<dyn Draw, 8, 1>::new();
+
+        let _point = rack.must_add(Point(1), |value| value);
+
+        assert_eq!(
+            rack.add(Point(2), |value| value).err(),
+            Some(AddUnitError::FullRack { capacity: 1 })
+        );
+    }
+
+    #[test]
+    fn downcast_dyn_units_of_any_to_their_types() {
+        let rack = DynRack::<dyn Any, 8, 4>::new();
+
+        let mut number = rack.must_add(5_i32, |value| value);
+        let flag = rack.must_add(true, |value| value);
+
+        assert_eq!(number.downcast_ref::<i32>(), Some(&5));
+        assert_eq!(flag.downcast_ref::<bool>(), Some(&true));
+        assert_eq!(number.downcast_ref::<bool>(), None);
+        assert_eq!(flag.downcast_ref::<i32>(), None);
+
+        *number.downcast_mut::<i32>().unwrap() += 1;
+        assert_eq!(number.downcast_ref::<i32>(), Some(&6));
+        assert_eq!(number.downcast_mut::<u32>(), None);
+    }
+
+    #[test]
+    fn drop_value_and_release_slot_with_dyn_unit() {
+        let value = Rc::new(1);
+        let rack = DynRack::<dyn Any, 8, 1>::new();
+
+        let unit = rack.must_add(Rc::clone(&value), |value| value);
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(unit);
+
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(rack.add(2, |value| value).is_ok());
+    }
+
+    #[test]
+    fn drop_value_and_release_slot_on_bad_coercion() {
+        use std::boxed::Box;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let value = Rc::new(1);
+        let rack = DynRack::<dyn Any, 8, 1>::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            rack.add(Rc::clone(&value), |_| Box::leak(Box::new(0)))
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(rack.add(2, |value| value).is_ok());
+    }
+
+    #[test]
+    fn read_back_byte_slices_of_different_lengths() {
+        let rack = InlineRack::<8, 4>::new();
+
+        let short = rack.must_add_slice(&[1, 2]);
+        let long = rack.must_add_slice(&[3, 4, 5, 6, 7, 8, 9, 10]);
+        let empty = rack.must_add_slice(&[]);
+
+        assert_eq!(&*short, [1, 2]);
+        assert_eq!(&*long, [3, 4, 5, 6, 7, 8, 9, 10]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn reject_slices_not_fitting_into_inline_rack_slots() {
+        let rack = InlineRack::<4, 1>::new();
+
+        assert_eq!(
+            rack.add_slice(&[0; 5]).err(),
+            Some(AddUnitError::OversizedValue)
+        );
+        assert_eq!(
+            rack.add_str("hello").err(),
+            Some(AddUnitError::OversizedValue)
+        );
+
+        let _unit = rack.must_add_str("hell");
+        assert_eq!(
+            rack.add_slice(&[0]).err(),
+            Some(AddUnitError::FullRack { capacity: 1 })
+        );
+    }
+
+    #[test]
+    fn release_slot_of_string_unit() {
+        let rack = InlineRack::<8, 1>::new();
+
+        let name = rack.must_add_str("héllo");
+        assert_eq!(&*name, "héllo");
+        drop(name);
+
+        assert!(rack.add_str("world").is_ok());
+    }
+}
@@ -0,0 +1,169 @@
+//! Units running a custom hook before their value gets dropped.
+
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+
+use crate::Unit;
+
+/// A [`Unit`](struct.Unit.html) running a finalizer on its value before the
+/// value gets dropped, obtained through
+/// [`Unit::with_finalizer`](struct.Unit.html#method.with_finalizer).
+///
+/// The finalizer runs exactly once, when the `Finalized` unit gets out of the
+/// scope. It does not run if the value is taken out using
+/// [`into_inner`](#method.into_inner).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// use core::cell::Cell;
+///
+/// let closed = Cell::new(false);
+/// let rack = Rack64::new();
+///
+/// let handle = rack.must_add(5).with_finalizer(|_| closed.set(true));
+/// assert!(!closed.get());
+///
+/// drop(handle);
+/// assert!(closed.get());
+/// ```
+pub struct Finalized<'a, T, F: FnOnce(&mut T)> {
+    unit: ManuallyDrop<Unit<'a, T>>,
+    finalizer: ManuallyDrop<F>,
+}
+
+impl<'a, T, F: FnOnce(&mut T)> Finalized<'a, T, F> {
+    pub(crate) fn new(unit: Unit<'a, T>, finalizer: F) -> Self {
+        Self {
+            unit: ManuallyDrop::new(unit),
+            finalizer: ManuallyDrop::new(finalizer),
+        }
+    }
+
+    /// Consume the unit and return the stored value without running the
+    /// finalizer, freeing its slot on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let handle = rack
+    ///     .must_add(5)
+    ///     .with_finalizer(|_| panic!("not finalized"));
+    ///
+    /// assert_eq!(handle.into_inner(), 5);
+    /// ```
+    pub fn into_inner(self) -> T {
+        let mut finalized = ManuallyDrop::new(self);
+        // This is safe since both fields are taken exactly once and the
+        // finalized unit itself is never dropped.
+        let (unit, finalizer) = unsafe {
+            (
+                ManuallyDrop::take(&mut finalized.unit),
+                ManuallyDrop::take(&mut finalized.finalizer),
+            )
+        };
+        drop(finalizer);
+        unit.into_inner()
+    }
+}
+
+impl<T, F: FnOnce(&mut T)> Drop for Finalized<'_, T, F> {
+    fn drop(&mut self) {
+        // The unit is dropped even if the finalizer panics, releasing the slot
+        // of the value.
+        struct DropUnit<'u, 'a, T>(&'u mut ManuallyDrop<Unit<'a, T>>);
+
+        impl<T> Drop for DropUnit<'_, '_, T> {
+            fn drop(&mut self) {
+                // This is safe since the unit is dropped only here.
+                unsafe {
+                    ManuallyDrop::drop(self.0);
+                }
+            }
+        }
+
+        // This is safe since the finalizer is taken only here.
+        let finalizer = unsafe { ManuallyDrop::take(&mut self.finalizer) };
+        let unit = DropUnit(&mut self.unit);
+        finalizer(unit.0.get_mut());
+    }
+}
+
+impl<T, F: FnOnce(&mut T)> Deref for Finalized<'_, T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.unit.get_ref()
+    }
+}
+
+impl<T, F: FnOnce(&mut T)> DerefMut for Finalized<'_, T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.unit.get_mut()
+    }
+}
+
+impl<T: fmt::Debug, F: FnOnce(&mut T)> fmt::Debug for Finalized<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Finalized")
+            .field(self.unit.get_ref())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Rack, Rack1};
+
+    use core::cell::Cell;
+
+    #[test]
+    fn run_finalizer_once_before_dropping_value() {
+        let finalized = Cell::new(0);
+        let rack = Rack1::new();
+
+        let mut unit = rack.must_add(5).with_finalizer(|value| {
+            assert_eq!(*value, 6);
+            finalized.set(finalized.get() + 1);
+        });
+        *unit += 1;
+        assert_eq!(finalized.get(), 0);
+        drop(unit);
+
+        assert_eq!(finalized.get(), 1);
+        assert!(rack.add(7).is_ok());
+    }
+
+    #[test]
+    fn skip_finalizer_when_value_is_taken_out() {
+        let finalized = Cell::new(0);
+        let rack = Rack1::new();
+
+        let unit = rack
+            .must_add(5)
+            .with_finalizer(|_| finalized.set(finalized.get() + 1));
+
+        assert_eq!(unit.into_inner(), 5);
+        assert_eq!(finalized.get(), 0);
+        assert!(rack.add(7).is_ok());
+    }
+
+    #[test]
+    fn release_slot_even_if_finalizer_panics() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let rack = Rack1::new();
+
+        let unit = rack.must_add(5).with_finalizer(|_| panic!("finalizer"));
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(unit))).is_err());
+
+        assert!(rack.add(6).is_ok());
+    }
+}
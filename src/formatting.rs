@@ -0,0 +1,48 @@
+//! Formatting of units using [ufmt](https://docs.rs/ufmt), enabled by the
+//! `ufmt` feature.
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::Unit;
+
+/// Units are formatted transparently as the values they hold.
+impl<T: uDisplay> uDisplay for Unit<'_, T> {
+    fn fmt<W>(&self, formatter: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDisplay::fmt(self.get_ref(), formatter)
+    }
+}
+
+/// Units are formatted transparently as the values they hold.
+impl<T: uDebug> uDebug for Unit<'_, T> {
+    fn fmt<W>(&self, formatter: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDebug::fmt(self.get_ref(), formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::String;
+
+    use ufmt::uwrite;
+
+    use crate::{Rack, Rack64};
+
+    #[test]
+    fn format_unit_holding_formattable_value() {
+        let rack = Rack64::new();
+        let unit = rack.must_add(5_u32);
+
+        let mut output = String::new();
+        uwrite!(output, "{} {:?}", unit, unit).unwrap();
+
+        assert_eq!(output, "5 5");
+    }
+}
@@ -0,0 +1,144 @@
+//! A rack for values living until the end of the program.
+
+use core::mem::ManuallyDrop;
+
+use crate::{AddUnitError, SyncRack};
+
+/// A rack handing out `'static` references to values stored on it, meant to
+/// be kept in a `static` item.
+///
+/// Values added to the rack are never dropped and their slots are never
+/// reclaimed. This suits global state which is set once, even without a heap
+/// to leak it to. The rack is backed by a [`SyncRack`](struct.SyncRack.html),
+/// so values may be added to it from multiple threads at once.
+///
+/// # Examples
+///
+/// Store a configuration once and read it from anywhere:
+///
+/// ```
+/// # use heapnotize::*;
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// static CONFIGS: StaticRack<Config, 1> = StaticRack::new();
+///
+/// let config: &'static Config = CONFIGS.must_add_static(Config { verbose: true });
+/// assert!(config.verbose);
+/// ```
+pub struct StaticRack<T, const N: usize> {
+    rack: SyncRack<T, N>,
+}
+
+impl<T, const N: usize> StaticRack<T, N> {
+    /// Initialize a new static Rack with a capacity of `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// static RACK: StaticRack<i32, 64> = StaticRack::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            rack: SyncRack::new(),
+        }
+    }
+
+    /// Add a value to the `Rack` for the rest of the program and return an
+    /// error if it is full.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// static RACK: StaticRack<i32, 1> = StaticRack::new();
+    ///
+    /// let five = RACK.add_static(5).unwrap();
+    /// *five += 1;
+    /// assert_eq!(*five, 6);
+    ///
+    /// assert_eq!(RACK.add_static(7), Err(AddUnitError::FullRack { capacity: 1 }));
+    /// ```
+    // Each call hands out a reference to a different slot, so the returned
+    // references never alias.
+    #[allow(clippy::mut_from_ref)]
+    pub fn add_static(&'static self, value: T) -> Result<&'static mut T, AddUnitError> {
+        let mut unit = ManuallyDrop::new(self.rack.add(value)?);
+        // The unit is never dropped, therefore its slot is never released and
+        // nobody else can ever access it. The rack lives for the rest of the
+        // program.
+        Ok(unsafe { &mut *(unit.get_mut() as *mut T) })
+    }
+
+    /// Add a value to the `Rack` for the rest of the program and panic if it
+    /// is full.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// static RACK: StaticRack<i32, 64> = StaticRack::new();
+    ///
+    /// let five = RACK.must_add_static(5);
+    /// assert_eq!(*five, 5);
+    /// ```
+    pub fn must_add_static(&'static self, value: T) -> &'static mut T {
+        self.add_static(value).expect("The rack is full")
+    }
+}
+
+impl<T, const N: usize> Default for StaticRack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn read_value_stored_once_through_static_reference() {
+        static CONFIGS: StaticRack<(&str, u32), 2> = StaticRack::new();
+
+        let config = CONFIGS.must_add_static(("baud", 9600));
+        let reader = std::thread::spawn(move || config.1);
+
+        assert_eq!(reader.join().unwrap(), 9600);
+    }
+
+    #[test]
+    fn never_drop_values_stored_for_rest_of_program() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        struct Counted;
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        static RACK: StaticRack<Counted, 1> = StaticRack::new();
+
+        RACK.must_add_static(Counted);
+        assert_eq!(
+            RACK.add_static(Counted).err(),
+            Some(AddUnitError::FullRack { capacity: 1 })
+        );
+
+        // Only the value which did not fit into the rack was dropped.
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,53 @@
+//! Handles to values owned by a rack.
+
+/// A handle to a value stored on a rack through
+/// [`ArrayRack::insert`](struct.ArrayRack.html#method.insert).
+///
+/// Unlike [`Unit`](struct.Unit.html), a `Key` does not own the value, nor does
+/// it borrow the rack. It is a plain `Copy` handle which can be kept in other
+/// data structures, e.g. an adjacency list of a graph, and used to look the
+/// value up on the rack later.
+///
+/// # Generations
+///
+/// The key consists of the index of the slot and its generation. The
+/// generation of a slot is incremented every time the slot gets freed, no
+/// matter whether it was held by a key or a unit. Once the value is removed,
+/// all the keys pointing to it stop resolving, even after the slot gets
+/// reused by another value.
+///
+/// The generation is kept in 30 bits and wraps around. A stale key could
+/// therefore resolve again only after its slot was reused over a billion
+/// times. Keys are only meaningful for the rack which issued them.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let mut rack = Rack1::new();
+///
+/// let old = rack.must_insert(1);
+/// rack.remove(old);
+/// let new = rack.must_insert(2);
+///
+/// assert_eq!(old.index(), new.index());
+/// assert_ne!(old.generation(), new.generation());
+/// assert_eq!(rack.get(old), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub(crate) index: usize,
+    pub(crate) generation: u32,
+}
+
+impl Key {
+    /// Get the index of the slot holding the value.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the generation of the slot at the time the value was inserted.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
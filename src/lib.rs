@@ -8,12 +8,13 @@
 //! occupy its full size in the memory, so choose the capacity wisely. Unlike
 //! [`Box`](https://doc.rust-lang.org/std/boxed/index.html), a `Rack` can store
 //! only values of a single type. In case you want to store different types,
-//! define multiple instances of `Rack`. There are several implementations of
-//! `Rack` available with capacities of powers of 2, up to 1024:
-//! [`Rack1`](struct.Rack1.html), [`Rack2`](struct.Rack2.html),
-//! [`Rack4`](struct.Rack4.html), [`Rack8`](struct.Rack8.html),
-//! [`Rack16`](struct.Rack16.html), [`Rack32`](struct.Rack32.html), ... ,
-//! [`Rack1024`](struct.Rack1024.html).
+//! define multiple instances of `Rack`. The capacity of
+//! [`ArrayRack<T, N>`](struct.ArrayRack.html) is given by its `N`. For
+//! convenience, there are aliases available for capacities of powers of 2, up
+//! to 4096: [`Rack1`](type.Rack1.html), [`Rack2`](type.Rack2.html),
+//! [`Rack4`](type.Rack4.html), [`Rack8`](type.Rack8.html),
+//! [`Rack16`](type.Rack16.html), [`Rack32`](type.Rack32.html), ... ,
+//! [`Rack4096`](type.Rack4096.html).
 //!
 //! Learn more in the [documentation of the Rack trait](trait.Rack.html).
 //!
@@ -57,43 +58,142 @@
 
 #![no_std]
 
-mod data_array;
+mod aligned;
+mod bitmap;
+#[cfg(feature = "zerocopy")]
+mod bytes;
+mod chain;
+#[cfg(feature = "critical-section")]
+mod cs;
+mod dynamic;
+mod finalize;
+#[cfg(feature = "ufmt")]
+mod formatting;
+mod key;
+#[cfg(feature = "defmt")]
+mod logging;
+mod managed;
+mod observer;
+mod policy;
+mod ring;
+#[cfg(feature = "serde")]
+mod serialization;
+mod shared;
+mod slot;
+mod storage;
+#[cfg(feature = "heapless")]
+mod vec;
 
-use core::cell::{RefCell, RefMut};
+pub use aligned::{Align, AlignedRack, SupportedAlign};
+pub use chain::ChainRack;
+#[cfg(feature = "critical-section")]
+pub use cs::{CsRack, CsUnit};
+pub use dynamic::{DynRack, DynUnit, InlineRack, SliceUnit};
+pub use finalize::Finalized;
+pub use key::Key;
+pub use managed::ManagedUnit;
+pub use policy::{FirstFit, MostRecentlyFreed, Occupancy, SlotPolicy};
+pub use ring::RingRack;
+pub use shared::{Shared, SharedUnit, WeakUnit};
+pub use slot::Slot;
+pub use storage::Iter;
+
+#[cfg(target_has_atomic = "8")]
+mod global;
+#[cfg(any(target_has_atomic = "8", feature = "critical-section"))]
+mod sync;
+
+#[cfg(target_has_atomic = "8")]
+pub use global::StaticRack;
+#[cfg(any(target_has_atomic = "8", feature = "critical-section"))]
+pub use sync::{SlotFlag, SyncRack, SyncUnit};
+
+use crate::storage::{storage_methods, RackState, Storage};
+
+use core::cmp::Ordering;
 use core::fmt;
-use core::mem::MaybeUninit;
+use core::hash::{Hash, Hasher};
+use core::iter;
+use core::marker::PhantomData;
+use core::mem;
 use core::ops::Drop;
+use core::ops::{AddAssign, MulAssign, SubAssign};
 use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
 use core::ptr;
 
 /// An enumeration of possible errors which can happen when adding a new value
 /// to a [Rack](trait.Rack.html).
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AddUnitError {
     /// The [Rack](trait.Rack.html) is on its full capacity and cannot accept
     /// more values.
-    FullRack,
+    FullRack {
+        /// The number of values the rack can hold.
+        capacity: usize,
+    },
+    /// The value is too large or too strictly aligned to fit into a slot of
+    /// the [DynRack](struct.DynRack.html).
+    OversizedValue,
 }
 
 impl fmt::Display for AddUnitError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Self::FullRack => write!(f, "the rack is full"),
+            Self::FullRack { capacity } => write!(f, "the rack is full (capacity {})", capacity),
+            Self::OversizedValue => write!(f, "the value does not fit into a slot of the rack"),
         }
     }
 }
 
+/// An event happening on a [Rack](trait.Rack.html), reported to the observer
+/// passed to [`observe`](struct.ArrayRack.html#method.observe).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RackEvent {
+    /// A value was stored in the slot of the given index.
+    Allocated(usize),
+    /// The slot of the given index was released after its value was dropped
+    /// or taken out.
+    Freed(usize),
+}
+
+/// A summary of the occupancy of a [Rack](trait.Rack.html), obtained through
+/// [`stats`](struct.ArrayRack.html#method.stats).
+///
+/// Byte counts include the overhead of each slot, see
+/// [`value_stride`](struct.ArrayRack.html#method.value_stride).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RackStats {
+    /// The number of slots of the rack.
+    pub capacity: usize,
+    /// The number of slots holding a value.
+    pub used: usize,
+    /// The number of slots holding no value.
+    pub free: usize,
+    /// The number of bytes taken by the slots holding a value.
+    pub bytes_used: usize,
+    /// The number of bytes taken by all the slots of the rack.
+    pub bytes_capacity: usize,
+}
+
 /// A trait specifying functions and methods for initialization of a `Rack` and
 /// for storing values in it.
 ///
 /// # Capacity
 ///
 /// A `Rack` keep an allocated memory on the stack for values to be stored in.
-/// It has several implementations varying in the capacity they provide:
-/// [`Rack1`](struct.Rack1.html), [`Rack2`](struct.Rack2.html),
-/// [`Rack4`](struct.Rack4.html), [`Rack8`](struct.Rack8.html),
-/// [`Rack16`](struct.Rack16.html), [`Rack32`](struct.Rack32.html), ... ,
-/// [`Rack1024`](struct.Rack1024.html).
+/// Its capacity is given by the `N` of [`ArrayRack<T, N>`](struct.ArrayRack.html).
+/// There are aliases available for capacities of powers of 2:
+/// [`Rack1`](type.Rack1.html), [`Rack2`](type.Rack2.html),
+/// [`Rack4`](type.Rack4.html), [`Rack8`](type.Rack8.html),
+/// [`Rack16`](type.Rack16.html), [`Rack32`](type.Rack32.html), ... ,
+/// [`Rack4096`](type.Rack4096.html). For generic code, there is also
+/// [`Rack0`](struct.Rack0.html) which cannot hold any values.
+/// Values needing a larger alignment than their type requires can be stored
+/// on [`AlignedRack`](struct.AlignedRack.html).
+///
+/// Keep in mind that the stack is usually not very large. Big racks of big
+/// values may easily cause a stack overflow.
 ///
 /// # Stored type
 ///
@@ -102,13 +202,73 @@ impl fmt::Display for AddUnitError {
 /// able to deduce the type on its own based on the code adding values to the
 /// `Rack`.
 ///
+/// # Generic construction
+///
+/// The trait does not provide a constructor, since
+/// [`RackSlice`](struct.RackSlice.html) can only be created from a borrowed
+/// buffer. Racks owning their storage implement `Default` instead, so generic
+/// code can construct them by requiring it next to the `Rack` trait:
+///
+/// ```
+/// # use heapnotize::*;
+/// fn make<R: Rack<i32> + Default>() -> R {
+///     R::default()
+/// }
+///
+/// let rack: Rack64<i32> = make();
+/// let five = rack.must_add(5);
+/// ```
+///
 /// # Memory requirements
 ///
 /// Unlike a basic array, `Rack` is not zero-cost when it comes to memory
-/// requirements. The formula for the memory requirements of a rack is
-/// following:
+/// requirements. Each slot carries a four byte tag keeping its state and
+/// generation, padded to the alignment of the value, and the `Rack` keeps a
+/// bitmap of fully occupied groups of slots, next to a pointer to the observer
+/// passed to [`observe`](struct.ArrayRack.html#method.observe) and a
+/// reference back to itself used by
+/// [`Unit::try_clone`](struct.Unit.html#method.try_clone). Thanks to the
+/// bitmap, looking for a free slot scans at most a single group of
+/// `capacity / 64` slots, rather than the whole rack. The formula for the
+/// memory requirements of a rack is following:
+///
+/// **`capacity_of_the_rack * round_up_to_the_closest_multiple_of_align(round_up_to_the_closest_multiple_of_4(size_of(value)) + 4) + 48`**
+///
+/// Where the `align` is the alignment of the value, at least 4.
 ///
-/// **`capacity_of_the_rack * (round_up_to_the_closest_multiple_of_8(size_of(value)) + 8)`**
+/// Zero-sized values are not special-cased. The values themselves take no
+/// space, but each of their slots still carries the tag, so a rack of them
+/// takes four bytes per slot. The tag cannot be left out, it is needed to
+/// enforce the capacity and to tell units and keys apart.
+///
+/// Since each value is followed by the tag of its slot, the stored values do
+/// not form a contiguous `[T]`, even when the rack is full.
+///
+/// # Allocation order
+///
+/// Racks provided by this crate store a new value in the free slot with the
+/// lowest index by default, no matter whether it is held by a
+/// [`Unit`](struct.Unit.html) or owned by the rack through a
+/// [`Key`](struct.Key.html). Once a value is dropped, its exact slot becomes
+/// free again. Dropping a unit and adding a new value right away therefore
+/// reuses the same slot, unless there is a free slot with a lower index:
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+///
+/// let a = rack.must_add(1);
+/// let b = rack.must_add(2);
+/// let b_index = b.index();
+/// drop(b);
+///
+/// let c = rack.must_add(3);
+/// assert_eq!(c.index(), b_index);
+/// ```
+///
+/// [`ArrayRack`](struct.ArrayRack.html) can be set up to prefer the slot
+/// released most recently instead, see
+/// [`MostRecentlyFreed`](struct.MostRecentlyFreed.html).
 pub trait Rack<T> {
     /// Add a value to the `Rack` and return an error if it is full.
     ///
@@ -116,7 +276,7 @@ pub trait Rack<T> {
     ///
     /// This method will return an error in case the `Rack` is fully populated.
     /// If you don't expect it to ever fail, use
-    /// [`must_add`](trait.Rack.html#tymethod.must_add) instead.
+    /// [`must_add`](trait.Rack.html#method.must_add) instead.
     ///
     /// # Examples
     ///
@@ -129,14 +289,44 @@ pub trait Rack<T> {
     /// let rack = Rack64::new();
     /// let five = rack.must_add(5);
     /// ```
-    fn add(&self, value: T) -> Result<Unit<T>, AddUnitError>;
+    fn add(&self, value: T) -> Result<Unit<'_, T>, AddUnitError> {
+        self.add_or_return(value).map_err(|(_, error)| error)
+    }
+
+    /// Add a value to the `Rack` and hand the value back if it is full.
+    ///
+    /// This is the method implementations of the trait need to provide. Unlike
+    /// [`add`](trait.Rack.html#method.add), the value is not dropped when it
+    /// cannot be stored.
+    ///
+    /// It has no default implementation built on top of `add`, since `add`
+    /// drops the value once the rack turns out to be full and there would be
+    /// nothing to hand back. It is the other way around, `add` and the rest of
+    /// the provided methods are built on top of this one.
+    ///
+    /// # Errors
+    ///
+    /// This method will return the value together with an error in case the
+    /// `Rack` is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack1::new();
+    /// let _five = rack.must_add(5);
+    ///
+    /// let (six, _) = rack.add_or_return(6).unwrap_err();
+    /// assert_eq!(six, 6);
+    /// ```
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)>;
 
     /// Add a value to the `Rack` and panic if it is full.
     ///
     /// # Panics
     ///
     /// This method will panic in case the `Rack` is fully populated. If you
-    /// would rather receive an error, use [`add`](trait.Rack.html#tymethod.add)
+    /// would rather receive an error, use [`add`](trait.Rack.html#method.add)
     /// instead.
     ///
     /// # Examples
@@ -150,398 +340,4008 @@ pub trait Rack<T> {
     /// let rack = Rack64::new();
     /// let five = rack.add(5).unwrap();
     /// ```
-    fn must_add(&self, value: T) -> Unit<T>;
-}
-
-macro_rules! rack {
-    ($name:ident, $size:expr, $data_initializer:expr) => {
-        /// Implementation of [`Rack`](trait.Rack.html) trait holding up to N
-        /// values of a type T.
-        ///
-        /// See more in the [documentation of the `Rack`](trait.Rack.html) trait.
-        pub struct $name<T> {
-            // All the stored units are kept inside `RefCell` to allow us to
-            // keep a mutable reference to the data in multiple `Unit`s while
-            // keeping the `Rack` immutable. That way we avoid issues with
-            // borrow checking. The carried type is then enclosed in
-            // `MaybeUnit`, the reason for that we don't need to require carried
-            // type to implement `Copy` and `Default` to populate the whole
-            // array during `Rack`'s initialization.
-            data: [RefCell<MaybeUninit<T>>; $size],
-        }
-
-        impl<T> $name<T> {
-            /// Initialize a new Rack with a capacity based on the given implementation.
-            ///
-            /// # Examples
-            ///
-            /// Initialize a `Rack` holding up to 64 values of type `i32`:
-            ///
-            /// ```
-            /// # use heapnotize::*;
-            /// let rack = Rack64::<i32>::new();
-            /// ```
-            pub fn new() -> Self {
-                Self {
-                    data: $data_initializer,
-                }
-            }
-        }
-
-        impl<T> Rack<T> for $name<T> {
-            fn add(&self, value: T) -> Result<Unit<T>, AddUnitError> {
-                for cell in self.data.iter() {
-                    // If we can borrow it, nobody has a mutable reference, it is free
-                    // to take.
-                    if cell.try_borrow().is_ok() {
-                        cell.replace(MaybeUninit::new(value));
-                        return Ok(Unit {
-                            cell: cell.borrow_mut(),
-                        });
-                    }
-                }
-                Err(AddUnitError::FullRack)
-            }
-
-            fn must_add(&self, value: T) -> Unit<T> {
-                self.add(value).expect("The rack is full")
-            }
-        }
-
-        impl<T> Default for $name<T> {
-            fn default() -> Self {
-                Self::new()
-            }
-        }
-    };
-}
-rack!(Rack1, 1, data_array::init_1());
-rack!(Rack2, 2, data_array::init_2());
-rack!(Rack4, 4, data_array::init_4());
-rack!(Rack8, 8, data_array::init_8());
-rack!(Rack16, 16, data_array::init_16());
-rack!(Rack32, 32, data_array::init_32());
-rack!(Rack64, 64, data_array::init_64());
-rack!(Rack128, 128, data_array::init_128());
-rack!(Rack256, 256, data_array::init_256());
-rack!(Rack512, 512, data_array::init_512());
-rack!(Rack1024, 1024, data_array::init_1024());
-
-/// A type serving as an owner of a value stored on the
-/// [`Rack`](trait.Rack.html).
-///
-/// A `Unit` can be obtained by adding a value to the `Rack`. After that, it can
-/// be used to access the value, both mutably and immutably. Once the `Unit`
-/// gets out of the scope, the value that it holds gets dropped.
-#[derive(Debug)]
-pub struct Unit<'a, T> {
-    cell: RefMut<'a, MaybeUninit<T>>,
-}
+    fn must_add(&self, value: T) -> Unit<'_, T> {
+        self.add(value).expect("The rack is full")
+    }
 
-impl<T> Unit<'_, T> {
-    /// Get a reference to the data stored on the Rack.
+    /// Add a value to the `Rack` and return its unit together with the
+    /// generation of the slot it was stored in.
+    ///
+    /// The generation counts how many times the slot was released before, so
+    /// it tells apart values which reused the same slot, e.g. in logs. It
+    /// wraps around after `2^30` releases.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
     ///
     /// # Examples
     ///
-    /// Reference to the stored value can be accessed using this method:
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack1::new();
+    ///
+    /// let (five, generation) = rack.add_with_generation(5).unwrap();
+    /// assert_eq!(generation, 0);
+    /// drop(five);
+    ///
+    /// let (six, generation) = rack.add_with_generation(6).unwrap();
+    /// assert_eq!(generation, 1);
+    /// ```
+    fn add_with_generation(&self, value: T) -> Result<(Unit<'_, T>, u32), AddUnitError> {
+        self.add(value).map(|unit| {
+            let generation = unit.slot.generation();
+            (unit, generation)
+        })
+    }
+
+    /// Add a clone of the value to the `Rack` and return an error if it is
+    /// full.
+    ///
+    /// Racks provided by this crate clone the value only once they found a
+    /// free slot for it, so no clone is wasted on a full rack. Other
+    /// implementations of the trait may fall back to cloning it right away.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
     /// let rack = Rack64::new();
-    /// let five = rack.must_add(5);
-    /// assert_eq!(*five.get_ref(), 5);
+    ///
+    /// let original = [1, 2, 3];
+    /// let clone = rack.add_cloned(&original).unwrap();
+    ///
+    /// assert_eq!(*clone, original);
     /// ```
+    fn add_cloned(&self, value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        self.add(value.clone())
+    }
+
+    /// Add a clone of the value to the `Rack` and panic if it is full.
     ///
-    /// The stored value can be also accessed using a dereference `*`:
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is fully populated.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
     /// let rack = Rack64::new();
-    /// let five = rack.must_add(5);
-    /// assert_eq!(*five, 5);
+    ///
+    /// let original = [1, 2, 3];
+    /// let clone = rack.must_add_cloned(&original);
+    ///
+    /// assert_eq!(*clone, original);
     /// ```
+    fn must_add_cloned(&self, value: &T) -> Unit<'_, T>
+    where
+        T: Clone,
+    {
+        self.add_cloned(value).expect("The rack is full")
+    }
+
+    /// Add all the values of an array to the `Rack` and return an error if
+    /// they do not fit into it.
     ///
-    /// Finally, this allows users to use defer coercion and pass `&Unit<T>` to
-    /// functions accepting `&T`:
+    /// Either all the values are stored, or none of them is. Values are added
+    /// one by one. Should one of them not fit, the units added so far are
+    /// dropped, releasing their slots, before the error is returned. That
+    /// also covers a [`SlotPolicy`](trait.SlotPolicy.html) refusing to hand
+    /// out some of the free slots.
+    ///
+    /// It is not available on `dyn Rack`, since it is generic over the length
+    /// of the array.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` cannot hold all
+    /// the values. The values are dropped then.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
-    /// fn add_one(num: &i32) -> i32 {
-    ///     num + 1
-    /// }
+    /// let rack = Rack4::new();
     ///
-    /// let rack = Rack64::new();
-    /// let five = rack.must_add(5);
+    /// let [a, b, c] = rack.add_array([1, 2, 3]).unwrap();
+    /// assert_eq!((*a, *b, *c), (1, 2, 3));
     ///
-    /// assert_eq!(add_one(&five), 6)
+    /// assert!(rack.add_array([4, 5]).is_err());
+    /// assert!(rack.add(4).is_ok());
     /// ```
-    pub fn get_ref(&self) -> &T {
-        // This code is safe since we always populate the `MaybeUninit` with a
-        // value on `add` call before an `Unit` is returned.
-        unsafe { &*self.cell.as_ptr() }
+    fn add_array<const M: usize>(&self, values: [T; M]) -> Result<[Unit<'_, T>; M], AddUnitError>
+    where
+        Self: Sized,
+    {
+        let mut units = [(); M].map(|_| None);
+        for (unit, value) in units.iter_mut().zip(values) {
+            // Units added so far get dropped on failure, releasing their
+            // slots.
+            *unit = Some(self.add(value)?);
+        }
+        Ok(units.map(|unit| unit.unwrap_or_else(|| unreachable!("all the values were added"))))
     }
 
-    /// Get a mutable reference to the data stored on the Rack.
+    /// Populate the remaining free slots of the `Rack` with default values,
+    /// returning an iterator over their units.
     ///
-    /// # Examples
+    /// A value is added each time the iterator is advanced, so the rack is
+    /// full once the whole iterator is consumed. This suits pools which are
+    /// populated up front and then handed out.
     ///
-    /// Mutable reference to the stored value can be obtained using this method:
+    /// It is not available on `dyn Rack`, since the type of the returned
+    /// iterator is not known there.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
-    /// let rack = Rack64::new();
+    /// let rack = Rack4::<u32>::new();
     ///
-    /// let mut number = rack.must_add(5);
-    /// *number.get_mut() = 10;
+    /// let buffers: Vec<_> = rack.fill().collect();
+    /// assert_eq!(buffers.len(), 4);
+    /// assert!(rack.add(1).is_err());
+    /// ```
+    fn fill<'a>(&'a self) -> impl Iterator<Item = Unit<'a, T>>
+    where
+        Self: Sized,
+        T: Default + 'a,
+    {
+        iter::from_fn(move || self.add(T::default()).ok()).fuse()
+    }
+
+    /// Add a value with all of its bytes set to zero to the `Rack` and return
+    /// an error if it is full.
+    ///
+    /// The value is not constructed through `Default`, which makes this a
+    /// cheap way of preparing large buffers. Available with the `bytemuck`
+    /// feature.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(*number.get_ref(), 10);
     /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::<[u32; 256]>::new();
     ///
-    /// The stored value can be also changed directly using a dereference `*`:
+    /// let buffer = rack.add_zeroed().unwrap();
+    /// assert!(buffer.iter().all(|&word| word == 0));
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    fn add_zeroed(&self) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: bytemuck::Zeroable,
+    {
+        self.add(T::zeroed())
+    }
+
+    /// Add a value to the `Rack` to be owned by reference counted
+    /// [`SharedUnit`](struct.SharedUnit.html)s and return an error if it is
+    /// full.
+    ///
+    /// The rack must be holding values wrapped in
+    /// [`Shared`](struct.Shared.html), which keeps their reference count. It
+    /// is not available on `dyn Rack`, since it is generic over the type of
+    /// the shared value.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
-    /// let rack = Rack64::new();
+    /// let rack: Rack64<Shared<i32>> = Rack64::new();
     ///
-    /// let mut number = rack.must_add(5);
-    /// *number = 10;
+    /// let five = rack.add_shared(5).unwrap();
+    /// let also_five = five.clone();
     ///
-    /// assert_eq!(*number, 10);
+    /// assert_eq!(*also_five, 5);
     /// ```
+    fn add_shared<U>(&self, value: U) -> Result<SharedUnit<'_, U>, AddUnitError>
+    where
+        Self: Rack<Shared<U>> + Sized,
+    {
+        Rack::<Shared<U>>::add(self, Shared::new(value)).map(SharedUnit::from_unit)
+    }
+
+    /// Add a value to the `Rack` to be owned by reference counted
+    /// [`SharedUnit`](struct.SharedUnit.html)s and panic if it is full.
     ///
-    /// Finally, this allows users to use defer coercion and pass `&mut Unit<T>`
-    /// to functions accepting `&mut T`:
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is fully populated.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use heapnotize::*;
-    /// fn set_to_ten(num: &mut i32) {
-    ///     *num = 10;
-    /// }
-    ///
     /// let rack = Rack64::new();
     ///
-    /// let mut number = rack.must_add(5);
-    /// set_to_ten(&mut number);
-    ///
-    /// assert_eq!(*number, 10)
+    /// let five = rack.must_add_shared(5);
+    /// assert_eq!(*five, 5);
     /// ```
-    pub fn get_mut(&mut self) -> &mut T {
-        // This code is safe since we always populate the `MaybeUninit` with a
-        // value on `add` call before an `Unit` is returned.
-        unsafe { &mut *self.cell.as_mut_ptr() }
+    fn must_add_shared<U>(&self, value: U) -> SharedUnit<'_, U>
+    where
+        Self: Rack<Shared<U>> + Sized,
+    {
+        <Self as Rack<T>>::add_shared(self, value).expect("The rack is full")
     }
-}
 
-/// When the Unit gets out of scope, it will deallocate its space on the Rack
-/// and make sure that the stored value gets properly dropped.
-// Unit's value is carried inside `MaybeUninit`. `Drop` on `MaybeUninit` does
-// not do anything. Therefore, we have to implement the `Drop` trait, making
-// sure that a destructor is called on the carried payload.
-impl<T> Drop for Unit<'_, T> {
-    fn drop(&mut self) {
-        // This is safe since the Unit was the only owner of the stored data.
-        unsafe {
-            ptr::drop_in_place(self.cell.as_mut_ptr());
-        }
-    }
-}
+    /// Get the maximum number of values the `Rack` can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::<i32>::new();
+    /// assert_eq!(rack.capacity(), 64);
+    /// ```
+    fn capacity(&self) -> usize;
 
-impl<T> Deref for Unit<'_, T> {
-    type Target = T;
+    /// Get the number of bytes occupied by the `Rack`, including all the
+    /// overhead described in [memory requirements](trait.Rack.html#memory-requirements).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::<u64>::new();
+    /// assert_eq!(rack.memory_bytes(), 64 * 16 + 48);
+    /// ```
+    fn memory_bytes(&self) -> usize {
+        mem::size_of_val(self)
+    }
 
-    fn deref(&self) -> &Self::Target {
-        self.get_ref()
+    /// Get the number of bytes of the `Rack` which are available for the
+    /// stored values, leaving out all the overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::<u64>::new();
+    /// assert_eq!(rack.value_bytes(), 64 * 8);
+    /// ```
+    fn value_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
     }
 }
 
-impl<T> DerefMut for Unit<'_, T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.get_mut()
-    }
+/// Implementation of [`Rack`](trait.Rack.html) trait holding up to `N` values
+/// of a type `T`.
+///
+/// The capacity can be any number but zero. For convenience, there are aliases
+/// available for capacities of powers of 2: [`Rack1`](type.Rack1.html),
+/// [`Rack2`](type.Rack2.html), ... , [`Rack4096`](type.Rack4096.html).
+///
+/// See more in the [documentation of the `Rack`](trait.Rack.html) trait.
+///
+/// # Examples
+///
+/// Initialize a `Rack` holding up to 48 values of type `i32`:
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = ArrayRack::<i32, 48>::new();
+/// ```
+///
+/// A rack with no capacity is most likely a mistake, so it is rejected at
+/// compile time. Use [`Rack0`](struct.Rack0.html) if that is really intended:
+///
+/// ```compile_fail
+/// # use heapnotize::*;
+/// let rack = ArrayRack::<i32, 0>::new();
+/// ```
+///
+/// # Keys
+///
+/// Besides handing values over to [`Unit`](struct.Unit.html)s, the rack can
+/// keep ownership of them and give out a copyable [`Key`](struct.Key.html)
+/// instead, see [`insert`](struct.ArrayRack.html#method.insert).
+///
+/// Values still owned by the rack when it is dropped are leaked, their
+/// destructors are not run. Dropping them would require the values to outlive
+/// the rack, ruling out values holding units of the very same rack, such as
+/// the list in the [crate documentation](index.html). Use
+/// [`remove`](struct.ArrayRack.html#method.remove) or
+/// [`clear`](struct.ArrayRack.html#method.clear) to drop them before that, or
+/// create the rack through [`scope`](struct.ArrayRack.html#method.scope),
+/// which does it once the rack is no longer used.
+///
+/// # Slot policy
+///
+/// New values are stored in the free slot with the lowest index by default.
+/// Another [`SlotPolicy`](trait.SlotPolicy.html) can be picked through the
+/// last type parameter, e.g. to reuse the slot released most recently:
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = ArrayRack::<i32, 48, MostRecentlyFreed>::new();
+/// ```
+pub struct ArrayRack<T, const N: usize, P = FirstFit> {
+    // The bitmap allows a free slot to be found without looking through all
+    // the occupied ones.
+    slots: [Slot<T>; N],
+    state: RackState,
+    policy: PhantomData<P>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<T, const N: usize, P: SlotPolicy> ArrayRack<T, N, P> {
+    // Evaluated whenever a rack is initialized, failing the build for racks
+    // with no capacity.
+    const NON_EMPTY: () = assert!(
+        N != 0,
+        "The capacity of ArrayRack must not be zero, use Rack0 instead"
+    );
 
-    #[test]
-    fn initialize_rack() {
-        let _rack: Rack2<_> = Rack2::<i32>::new();
-    }
+    /// The number of bytes each slot takes on top of the value it holds.
+    ///
+    /// It follows the formula given in
+    /// [memory requirements](trait.Rack.html#memory-requirements), i.e.
+    /// `round_up_to_the_closest_multiple_of_align(round_up_to_the_closest_multiple_of_4(size_of(value)) + 4) - size_of(value)`,
+    /// and can be used to compute the cost of a rack in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// const OVERHEAD: usize = Rack64::<u64>::OVERHEAD_PER_SLOT;
+    /// assert_eq!(OVERHEAD, 8);
+    /// ```
+    pub const OVERHEAD_PER_SLOT: usize = mem::size_of::<Slot<T>>() - mem::size_of::<T>();
 
-    #[test]
-    fn add_unit_to_rack() {
-        let rack = Rack2::<i32>::new();
+    /// Initialize a new Rack with a capacity based on the given implementation.
+    ///
+    /// # Examples
+    ///
+    /// Initialize a `Rack` holding up to 64 values of type `i32`:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::<i32>::new();
+    /// ```
+    ///
+    /// The initialization can be done in a const context as well. Note that
+    /// the `Rack` is not thread-safe and thus it cannot be kept in a `static`
+    /// item, use [`SyncRack`](struct.SyncRack.html) for that:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// const RACK: Rack64<i32> = Rack64::new();
+    ///
+    /// let rack = RACK;
+    /// let five = rack.must_add(5);
+    /// ```
+    pub const fn new() -> Self {
+        let () = Self::NON_EMPTY;
+        Self {
+            slots: [const { Slot::new() }; N],
+            state: RackState::new(N),
+            policy: PhantomData,
+        }
+    }
+
+    /// Initialize a new Rack, pass it to the given closure and drop it once
+    /// the closure returns.
+    ///
+    /// Units cannot escape the closure, since they borrow the rack. Unlike
+    /// when the rack is simply dropped, values still owned by the rack through
+    /// [keys](struct.ArrayRack.html#keys) are dropped at the end of the scope
+    /// too, so nothing stored on the rack outlives it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let sum = Rack64::scope(|rack| {
+    ///     let a = rack.must_add(1);
+    ///     let b = rack.must_add(2);
+    ///     *a + *b
+    /// });
+    ///
+    /// assert_eq!(sum, 3);
+    /// ```
+    pub fn scope<F, Out>(f: F) -> Out
+    where
+        F: FnOnce(&Self) -> Out,
+    {
+        let mut rack = Self::new();
+        let out = f(&rack);
+        rack.clear();
+        out
+    }
+
+    storage_methods! {
+        rack: "let rack = Rack4::new();",
+        rack_mut: "let mut rack = Rack4::new();",
+    }
+
+    /// Create a new rack holding clones of the values owned by this one.
+    ///
+    /// The clones are stored in the same slots, so keys issued by this rack
+    /// resolve to the same values on the new one. Values held by units are
+    /// not cloned, since they cannot be read through a shared reference to
+    /// the rack, and their slots are free on the new rack. To get an empty
+    /// rack instead, use `clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let five = rack.must_insert(5);
+    ///
+    /// let copy = rack.clone_values();
+    ///
+    /// assert_eq!(copy.get(five), Some(&5));
+    /// ```
+    pub fn clone_values(&self) -> Self
+    where
+        T: Clone,
+    {
+        let clone = Self::new();
+        self.storage().clone_into(clone.storage());
+        clone
+    }
+
+    /// Move all the values owned by the rack to another rack, keeping them
+    /// owned by the other rack.
+    ///
+    /// This allows a pool to be resized by moving its values to a bigger
+    /// rack. Values are moved, not cloned, and they may end up at different
+    /// indexes, so keys issued by this rack do not refer to them anymore.
+    /// Values held by units stay in place.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the other rack fills up.
+    /// Values which did not fit into it stay on this rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut small = Rack2::new();
+    /// small.must_insert(1);
+    /// small.must_insert(2);
+    ///
+    /// let big = Rack4::new();
+    /// small.drain_into(&big).unwrap();
+    ///
+    /// assert!(small.values().next().is_none());
+    /// assert!(big.values().eq(&[1, 2]));
+    /// ```
+    pub fn drain_into<const M: usize, Q: SlotPolicy>(
+        &mut self,
+        target: &ArrayRack<T, M, Q>,
+    ) -> Result<(), AddUnitError> {
+        // This is safe since the rack is borrowed mutably.
+        unsafe { self.storage().drain_into(target.storage()) }
+    }
+
+    fn storage(&self) -> Storage<'_, Slot<T>, P> {
+        self.state.set_host(self);
+        Storage {
+            slots: &self.slots,
+            state: &self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize, P: SlotPolicy> Rack<T> for ArrayRack<T, N, P> {
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)> {
+        self.storage().add(value)
+    }
+
+    fn add_cloned(&self, value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        self.storage().add_cloned(value)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<'a, T, const N: usize, P: SlotPolicy> IntoIterator for &'a ArrayRack<T, N, P> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Racks are equal when they own equal values in the same slots. The order of
+/// values therefore matters. Slots held by units are treated as free, since
+/// their values cannot be read through a shared reference to the rack.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let a = Rack4::new();
+/// a.must_insert(1);
+/// a.must_insert(2);
+///
+/// let b = Rack4::new();
+/// b.must_insert(1);
+/// b.must_insert(2);
+///
+/// assert!(a == b);
+/// ```
+impl<T: PartialEq, const N: usize, P: SlotPolicy> PartialEq for ArrayRack<T, N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter_occupied().eq(other.iter_occupied())
+    }
+}
+
+impl<T: Eq, const N: usize, P: SlotPolicy> Eq for ArrayRack<T, N, P> {}
+
+/// Racks are hashed slot by slot, consistently with how they are compared.
+/// Only values owned by the rack are hashed, together with indexes of their
+/// slots. Free slots and slots held by units are never read.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// use std::collections::HashSet;
+///
+/// let a = Rack4::new();
+/// a.must_insert(1);
+///
+/// let b = Rack4::new();
+/// b.must_insert(1);
+///
+/// let mut seen = HashSet::new();
+/// seen.insert(&a);
+/// assert!(seen.contains(&b));
+/// ```
+impl<T: Hash, const N: usize, P: SlotPolicy> Hash for ArrayRack<T, N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.storage().hash(state)
+    }
+}
+
+/// Cloning a rack creates a new empty one of the same capacity, the same as
+/// `Default` does. Values are not
+/// copied, since units could not be handed over to the clone. Use
+/// [`clone_values`](struct.ArrayRack.html#method.clone_values) to copy values
+/// owned by the rack.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+/// let _five = rack.must_add(5);
+///
+/// let clone = rack.clone();
+///
+/// assert_eq!(clone.capacity(), 64);
+/// assert!(clone.iter_occupied().next().is_none());
+/// ```
+impl<T, const N: usize, P: SlotPolicy> Clone for ArrayRack<T, N, P> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, P: SlotPolicy> Default for ArrayRack<T, N, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation of [`Rack`](trait.Rack.html) trait storing values in a slice
+/// provided by the caller.
+///
+/// Unlike [`ArrayRack`](struct.ArrayRack.html) which carries its own storage,
+/// `RackSlice` only borrows it. That allows the storage to be placed anywhere,
+/// e.g. in a static buffer, where it would not occupy the stack. The capacity
+/// of the rack is given by the length of the slice.
+///
+/// # Examples
+///
+/// Store values in a buffer kept on the stack:
+///
+/// ```
+/// # use heapnotize::*;
+/// let mut buffer = [const { Slot::new() }; 16];
+/// let rack = RackSlice::new(&mut buffer);
+///
+/// let five = rack.must_add(5);
+/// assert_eq!(*five, 5);
+/// ```
+pub struct RackSlice<'s, T> {
+    slots: &'s mut [Slot<T>],
+    state: RackState,
+}
+
+impl<'s, T> RackSlice<'s, T> {
+    /// The number of bytes each slot takes on top of the value it holds.
+    ///
+    /// See [`ArrayRack::OVERHEAD_PER_SLOT`](struct.ArrayRack.html#associatedconstant.OVERHEAD_PER_SLOT).
+    pub const OVERHEAD_PER_SLOT: usize = mem::size_of::<Slot<T>>() - mem::size_of::<T>();
+
+    /// Initialize a new Rack over the given storage.
+    ///
+    /// All the slots of the storage are considered empty. Values left in them
+    /// by leaked units or by a previous rack are not dropped, they are simply
+    /// overwritten.
+    ///
+    /// Unlike constructors of the other racks, this is not a `const fn`, since
+    /// marking the slots free requires mutable references, which are available
+    /// in const functions only since Rust 1.83.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut buffer = [const { Slot::<i32>::new() }; 16];
+    /// let rack = RackSlice::new(&mut buffer);
+    /// ```
+    pub fn new(slots: &'s mut [Slot<T>]) -> Self {
+        slot::reset(slots);
+        Self {
+            state: RackState::new(slots.len()),
+            slots,
+        }
+    }
+
+    storage_methods! {
+        rack: "let mut buffer = [const { Slot::new() }; 4];\nlet rack = RackSlice::new(&mut buffer);",
+        rack_mut: "let mut buffer = [const { Slot::new() }; 4];\nlet mut rack = RackSlice::new(&mut buffer);",
+    }
+
+    fn storage(&self) -> Storage<'_, Slot<T>> {
+        self.state.set_host(self);
+        Storage {
+            slots: self.slots,
+            state: &self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+/// Racks are compared slot by slot, the same way as
+/// [`ArrayRack`](struct.ArrayRack.html)s.
+impl<T: PartialEq> PartialEq for RackSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter_occupied().eq(other.iter_occupied())
+    }
+}
+
+impl<T: Eq> Eq for RackSlice<'_, T> {}
+
+/// Racks are hashed slot by slot, the same way as
+/// [`ArrayRack`](struct.ArrayRack.html)s.
+impl<T: Hash> Hash for RackSlice<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.storage().hash(state)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RackSlice<'_, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Rack<T> for RackSlice<'_, T> {
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)> {
+        self.storage().add(value)
+    }
+
+    fn add_cloned(&self, value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        self.storage().add_cloned(value)
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// The borrowed storage is counted in as well.
+    fn memory_bytes(&self) -> usize {
+        mem::size_of_val(self) + mem::size_of_val(self.slots)
+    }
+}
+
+/// Implementation of [`Rack`](trait.Rack.html) trait which cannot hold any
+/// values.
+///
+/// Adding a value to this rack always fails. It can serve as a degenerate case
+/// in code generic over racks, e.g. to disable an allocation path.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack0::new();
+/// assert!(rack.add(5).is_err());
+/// ```
+pub struct Rack0<T> {
+    // There is no data to store, the marker only binds the type of values.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Rack0<T> {
+    /// Initialize a new Rack with no capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack0::<i32>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Rack<T> for Rack0<T> {
+    fn add_or_return(&self, value: T) -> Result<Unit<'_, T>, (T, AddUnitError)> {
+        Err((value, AddUnitError::FullRack { capacity: 0 }))
+    }
+
+    fn add_cloned(&self, _value: &T) -> Result<Unit<'_, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        Err(AddUnitError::FullRack { capacity: 0 })
+    }
+
+    fn capacity(&self) -> usize {
+        0
+    }
+}
+
+impl<T> Default for Rack0<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Define an alias of [`ArrayRack`](struct.ArrayRack.html) with a custom
+/// capacity.
+///
+/// The defined type is a regular `ArrayRack`, therefore it implements the
+/// [`Rack`](trait.Rack.html) trait and provides all the methods available to
+/// the predefined racks such as [`Rack64`](type.Rack64.html). The visibility of
+/// the alias can be optionally specified before its name.
+///
+/// # Memory requirements
+///
+/// The whole capacity is allocated once the `Rack` is created. The formula for
+/// the memory requirements of a rack is following:
+///
+/// **`capacity_of_the_rack * round_up_to_the_closest_multiple_of_align(round_up_to_the_closest_multiple_of_4(size_of(value)) + 4) + 48`**
+///
+/// Where the `align` is the alignment of the value, at least 4.
+///
+/// Zero-sized values are not special-cased. The values themselves take no
+/// space, but each of their slots still carries the tag, so a rack of them
+/// takes four bytes per slot. The tag cannot be left out, it is needed to
+/// enforce the capacity and to tell units and keys apart.
+///
+/// # Examples
+///
+/// Define a rack holding up to 96 values, instead of wasting memory on a
+/// `Rack128`:
+///
+/// ```
+/// # use heapnotize::*;
+/// define_rack!(pub Rack96, 96);
+///
+/// let rack = Rack96::new();
+/// let five = rack.must_add(5);
+/// assert_eq!(*five, 5);
+/// ```
+#[macro_export]
+macro_rules! define_rack {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $size:expr) => {
+        $(#[$meta])*
+        $vis type $name<T> = $crate::ArrayRack<T, $size>;
+    };
+}
+
+macro_rules! rack {
+    ($name:ident, $size:expr $(, $(#[$meta:meta])*)?) => {
+        define_rack!(
+            #[doc = concat!(
+                "An [`ArrayRack`](struct.ArrayRack.html) holding up to ",
+                stringify!($size),
+                " values of a type T."
+            )]
+            $($(#[$meta])*)?
+            pub $name,
+            $size
+        );
+    };
+}
+rack!(Rack1, 1);
+rack!(Rack2, 2);
+rack!(Rack4, 4);
+rack!(Rack8, 8);
+rack!(Rack16, 16);
+rack!(Rack32, 32);
+rack!(Rack64, 64);
+rack!(Rack128, 128);
+rack!(Rack256, 256);
+rack!(Rack512, 512);
+rack!(Rack1024, 1024);
+rack!(
+    Rack2048,
+    2048,
+    ///
+    /// This is a large allocation, make sure that the stack can fit it. For
+    /// example, a rack of 64-bit integers occupies 32 kB.
+);
+rack!(
+    Rack4096,
+    4096,
+    ///
+    /// This is a large allocation, make sure that the stack can fit it. For
+    /// example, a rack of 64-bit integers occupies 64 kB.
+);
+
+/// Get the capacity of the smallest predefined rack holding at least the
+/// given number of values, i.e. the closest power of 2.
+///
+/// This is used by [`rack_for!`](macro.rack_for.html).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// assert_eq!(smallest_rack_capacity(70), 128);
+/// assert_eq!(smallest_rack_capacity(64), 64);
+/// ```
+pub const fn smallest_rack_capacity(capacity: usize) -> usize {
+    capacity.next_power_of_two()
+}
+
+/// Get the type of the smallest predefined rack holding at least the given
+/// number of values.
+///
+/// The capacity must be a constant. The type of stored values can be given
+/// before it, otherwise it is left to be inferred. The resulting type is the
+/// same as the rack alias of the closest power of 2, e.g.
+/// [`Rack128`](type.Rack128.html) for a capacity of 70.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// const NEEDED: usize = 70;
+///
+/// let rack: rack_for!(NEEDED) = ArrayRack::new();
+/// let five = rack.must_add(5);
+///
+/// let rack: Rack128<i32> = <rack_for!(i32, NEEDED)>::new();
+/// ```
+#[macro_export]
+macro_rules! rack_for {
+    ($capacity:expr) => {
+        $crate::ArrayRack<_, { $crate::smallest_rack_capacity($capacity) }>
+    };
+    ($type:ty, $capacity:expr) => {
+        $crate::ArrayRack<$type, { $crate::smallest_rack_capacity($capacity) }>
+    };
+}
+
+/// Declare a rack and run a block using it, dropping the rack at its end.
+///
+/// The rack is given either by its type, or by the type of stored values and
+/// the needed capacity, in which case the smallest fitting rack is picked
+/// using [`rack_for!`](macro.rack_for.html). The macro evaluates to the value
+/// of the block. Units cannot be returned from it, since they borrow the
+/// rack.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// struct Event(u32);
+///
+/// let code = with_rack!(let pool: Rack64<Event>; {
+///     let event = pool.must_add(Event(5));
+///     event.0
+/// });
+/// assert_eq!(code, 5);
+///
+/// let sum = with_rack!(let pool: i32, 70; {
+///     assert_eq!(pool.capacity(), 128);
+///     *pool.must_add(1) + *pool.must_add(2)
+/// });
+/// assert_eq!(sum, 3);
+/// ```
+#[macro_export]
+macro_rules! with_rack {
+    (let $pool:ident: $rack:ty; $body:block) => {{
+        let $pool: $rack = ::core::default::Default::default();
+        // Temporaries of the block are dropped here, before the rack.
+        let out = $body;
+        out
+    }};
+    (let $pool:ident: $type:ty, $capacity:expr; $body:block) => {
+        $crate::with_rack!(let $pool: $crate::rack_for!($type, $capacity); $body)
+    };
+}
+
+/// A type serving as an owner of a value stored on the
+/// [`Rack`](trait.Rack.html).
+///
+/// A `Unit` can be obtained by adding a value to the `Rack`. After that, it can
+/// be used to access the value, both mutably and immutably. Once the `Unit`
+/// gets out of the scope, the value that it holds gets dropped.
+///
+/// # Size
+///
+/// A `Unit` takes three machine words, no matter the type of the value: a
+/// reference to its slot, the index of the slot and a reference to the state
+/// of the rack, i.e. its bitmap, observer and the pointer back to the rack.
+///
+/// ```
+/// # use heapnotize::*;
+/// use core::mem::size_of;
+///
+/// assert_eq!(size_of::<Unit<u8>>(), 3 * size_of::<usize>());
+/// ```
+pub struct Unit<'a, T> {
+    slot: &'a Slot<T>,
+    // Position of the occupied slot in the `Rack`, used to mark the slot free
+    // in the state of the rack once the unit is dropped.
+    index: usize,
+    state: &'a RackState,
+}
+
+// Units are passed around by value, so their size must not grow unnoticed.
+// Should a new field be really needed, update the bound and the documented
+// size of `Unit` together.
+const _: () = assert!(mem::size_of::<Unit<u8>>() <= 3 * mem::size_of::<usize>());
+
+impl<'a, T> Unit<'a, T> {
+    /// Get a reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// Reference to the stored value can be accessed using this method:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let five = rack.must_add(5);
+    /// assert_eq!(*five.get_ref(), 5);
+    /// ```
+    ///
+    /// The stored value can be also accessed using a dereference `*`:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let five = rack.must_add(5);
+    /// assert_eq!(*five, 5);
+    /// ```
+    ///
+    /// Finally, this allows users to use defer coercion and pass `&Unit<T>` to
+    /// functions accepting `&T`:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// fn add_one(num: &i32) -> i32 {
+    ///     num + 1
+    /// }
+    ///
+    /// let rack = Rack64::new();
+    /// let five = rack.must_add(5);
+    ///
+    /// assert_eq!(add_one(&five), 6)
+    /// ```
+    pub fn get_ref(&self) -> &T {
+        // This code is safe since we always populate the slot with a value on
+        // `add` call before an `Unit` is returned and the unit is its only
+        // owner.
+        unsafe { &*self.slot.as_ptr() }
+    }
+
+    /// Get a mutable reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// Mutable reference to the stored value can be obtained using this method:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// *number.get_mut() = 10;
+    ///
+    /// assert_eq!(*number.get_ref(), 10);
+    /// ```
+    ///
+    /// The stored value can be also changed directly using a dereference `*`:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// *number = 10;
+    ///
+    /// assert_eq!(*number, 10);
+    /// ```
+    ///
+    /// Finally, this allows users to use defer coercion and pass `&mut Unit<T>`
+    /// to functions accepting `&mut T`:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// fn set_to_ten(num: &mut i32) {
+    ///     *num = 10;
+    /// }
+    ///
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// set_to_ten(&mut number);
+    ///
+    /// assert_eq!(*number, 10)
+    /// ```
+    pub fn get_mut(&mut self) -> &mut T {
+        // This code is safe since we always populate the slot with a value on
+        // `add` call before an `Unit` is returned and the unit is its only
+        // owner.
+        unsafe { &mut *self.slot.as_ptr() }
+    }
+
+    /// Get a copy of the value stored on the Rack, keeping the unit intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let mut unit = rack.must_add(5);
+    ///
+    /// let n = unit.copied();
+    /// *unit += 1;
+    ///
+    /// assert_eq!(n, 5);
+    /// assert_eq!(*unit, 6);
+    /// ```
+    pub fn copied(&self) -> T
+    where
+        T: Copy,
+    {
+        *self.get_ref()
+    }
+
+    /// Get a clone of the value stored on the Rack, keeping the unit intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let mut unit = rack.must_add([1, 2]);
+    ///
+    /// let array = unit.cloned();
+    /// unit[0] = 3;
+    ///
+    /// assert_eq!(array, [1, 2]);
+    /// assert_eq!(*unit, [3, 2]);
+    /// ```
+    pub fn cloned(&self) -> T
+    where
+        T: Clone,
+    {
+        self.get_ref().clone()
+    }
+
+    /// Transform the stored value in place.
+    ///
+    /// The value is moved out of its slot, passed to the given closure and the
+    /// result is written back to the very same slot. The `Unit` keeps
+    /// occupying its original place on the Rack.
+    ///
+    /// # Panics
+    ///
+    /// If the closure panics, there is no value left to put back to the slot.
+    /// Rather than leaving the `Unit` pointing to a value which was already
+    /// dropped, the program is aborted.
+    ///
+    /// # Examples
+    ///
+    /// Square a stored number:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// number.map(|x| x * x);
+    ///
+    /// assert_eq!(*number, 25);
+    /// ```
+    pub fn map<F: FnOnce(T) -> T>(&mut self, f: F) {
+        // Panicking while the stack is already unwinding aborts the program.
+        // The guard is forgotten once the closure returns successfully.
+        struct AbortOnUnwind;
+
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                panic!("Unit::map closure panicked, aborting");
+            }
+        }
+
+        let value_ptr = self.as_mut_ptr();
+        let guard = AbortOnUnwind;
+        // This is safe since the value is read exactly once and the slot is
+        // populated again before anyone can access it. Should the closure
+        // panic, the guard aborts the program before the `Unit` gets dropped.
+        unsafe {
+            ptr::write(value_ptr, f(ptr::read(value_ptr)));
+        }
+        mem::forget(guard);
+    }
+
+    /// Transform the stored value in place with a closure which may fail.
+    ///
+    /// Unlike [`map`](struct.Unit.html#method.map), the closure only borrows
+    /// the current value. That way the original value stays untouched in its
+    /// slot when the closure returns an error or panics. On success, the
+    /// original value is dropped and replaced by the new one.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by the closure is passed through to the caller.
+    ///
+    /// # Examples
+    ///
+    /// Increment a stored number unless it would overflow:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(254u8);
+    /// assert!(number.try_map(|x| x.checked_add(1).ok_or("overflow")).is_ok());
+    /// assert_eq!(*number, 255);
+    ///
+    /// assert!(number.try_map(|x| x.checked_add(1).ok_or("overflow")).is_err());
+    /// assert_eq!(*number, 255);
+    /// ```
+    pub fn try_map<E, F: FnOnce(&T) -> Result<T, E>>(&mut self, f: F) -> Result<(), E> {
+        let value = f(self.get_ref())?;
+        *self.get_mut() = value;
+        Ok(())
+    }
+
+    /// Call a closure with a reference to the stored value and return the
+    /// unit unchanged.
+    ///
+    /// This mirrors [`Iterator::inspect`] and allows peeking at values in the
+    /// middle of an expression, e.g. for logging.
+    ///
+    /// # Examples
+    ///
+    /// Log nodes of a list while building it:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// enum List<'a> {
+    ///     Cons(i32, Unit<'a, List<'a>>),
+    ///     Nil,
+    /// }
+    ///
+    /// let rack = Rack64::new();
+    /// let mut log = Vec::new();
+    ///
+    /// let nil = rack.must_add(List::Nil).inspect(|_| log.push("nil"));
+    /// let list = rack
+    ///     .must_add(List::Cons(1, nil))
+    ///     .inspect(|_| log.push("cons"));
+    ///
+    /// assert!(matches!(*list, List::Cons(1, _)));
+    /// assert_eq!(log, ["nil", "cons"]);
+    /// ```
+    pub fn inspect<F: FnOnce(&T)>(self, f: F) -> Self {
+        f(self.get_ref());
+        self
+    }
+
+    /// Swap values stored in two units.
+    ///
+    /// The values are exchanged in place, both units keep occupying their
+    /// original slots. The units may come from different racks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut a = rack.must_add(1);
+    /// let mut b = rack.must_add(2);
+    /// a.swap(&mut b);
+    ///
+    /// assert_eq!(*a, 2);
+    /// assert_eq!(*b, 1);
+    /// ```
+    pub fn swap(&mut self, other: &mut Unit<'_, T>) {
+        mem::swap(self.get_mut(), other.get_mut());
+    }
+
+    /// Get a handle to a part of the stored value, e.g. a single field of a
+    /// struct.
+    ///
+    /// The returned [`Projection`](struct.Projection.html) borrows the unit
+    /// mutably, so the rest of the value cannot be accessed while it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// struct Player {
+    ///     name: &'static str,
+    ///     score: u32,
+    /// }
+    ///
+    /// fn reward(mut score: Projection<u32>) {
+    ///     *score += 10;
+    /// }
+    ///
+    /// let rack = Rack64::new();
+    /// let mut player = rack.must_add(Player { name: "p1", score: 0 });
+    ///
+    /// reward(player.project(|player| &mut player.score));
+    ///
+    /// assert_eq!(player.score, 10);
+    /// ```
+    pub fn project<U, F: FnOnce(&mut T) -> &mut U>(&mut self, f: F) -> Projection<'_, U> {
+        Projection {
+            value: f(self.get_mut()),
+        }
+    }
+
+    /// Get mutable references to two disjoint parts of the stored value at
+    /// once.
+    ///
+    /// Both references are picked by a single closure, so the borrow checker
+    /// can verify that they do not overlap. Selecting them by two separate
+    /// closures would hand each of them a mutable reference to the whole
+    /// value, which could not be checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let mut unit = rack.must_add((1, 2));
+    ///
+    /// let (a, b) = unit.get_disjoint_mut(|pair| (&mut pair.0, &mut pair.1));
+    /// core::mem::swap(a, b);
+    ///
+    /// assert_eq!(*unit, (2, 1));
+    /// ```
+    pub fn get_disjoint_mut<A: ?Sized, B: ?Sized, F>(&mut self, f: F) -> (&mut A, &mut B)
+    where
+        F: FnOnce(&mut T) -> (&mut A, &mut B),
+    {
+        f(self.get_mut())
+    }
+
+    /// Store a clone of the value in the same rack the unit was allocated
+    /// from.
+    ///
+    /// The original unit stays valid and independent of the new one. Unlike
+    /// [`try_duplicate`](#method.try_duplicate), this does not need the rack
+    /// to be passed around, since the unit reaches it through the state of the
+    /// rack it refers to anyway. The value is cloned before a free slot is
+    /// looked for, the clone is dropped if there is none.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the rack is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack2::new();
+    ///
+    /// let five = rack.must_add(5);
+    /// let copy = five.try_clone().unwrap();
+    ///
+    /// assert_eq!(*copy, 5);
+    /// assert_eq!(
+    ///     five.try_clone().unwrap_err(),
+    ///     AddUnitError::FullRack { capacity: 2 }
+    /// );
+    /// ```
+    pub fn try_clone(&self) -> Result<Unit<'a, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        // This is safe since the unit borrows the rack for `'a`, so the rack
+        // stays in place and the new unit may borrow it for as long.
+        let (slot, index) = unsafe { self.state.add_to_host(self.get_ref().clone())? };
+        Ok(Unit {
+            slot,
+            index,
+            state: self.state,
+        })
+    }
+
+    /// Store a clone of the value in another rack, or in the same one.
+    ///
+    /// The original unit stays valid and independent of the new one. The
+    /// value is cloned through [`add_cloned`](trait.Rack.html#method.add_cloned)
+    /// of the destination rack.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the destination rack is
+    /// fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add(5);
+    /// let mut copy = five.try_duplicate(&rack).unwrap();
+    /// *copy += 1;
+    ///
+    /// assert_eq!(*five, 5);
+    /// assert_eq!(*copy, 6);
+    /// ```
+    pub fn try_duplicate<'b, R: Rack<T> + ?Sized>(
+        &self,
+        dest: &'b R,
+    ) -> Result<Unit<'b, T>, AddUnitError>
+    where
+        T: Clone,
+    {
+        dest.add_cloned(self.get_ref())
+    }
+
+    /// Move the stored value to another rack.
+    ///
+    /// The value is added to the destination rack and the slot it occupied
+    /// on the original one is freed. A new `Unit` bound to the destination
+    /// rack is returned.
+    ///
+    /// # Errors
+    ///
+    /// In case the destination rack is full, the original `Unit` is returned
+    /// together with the error, still holding the value in its slot.
+    ///
+    /// # Examples
+    ///
+    /// Move a value out of a full rack into a larger one:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let small = Rack2::new();
+    /// let large = Rack4::new();
+    ///
+    /// let five = small.must_add(5);
+    /// let _six = small.must_add(6);
+    ///
+    /// let five = five.migrate(&large).unwrap();
+    /// assert_eq!(*five, 5);
+    /// assert!(small.add(7).is_ok());
+    /// ```
+    pub fn migrate<'b, R: Rack<T> + ?Sized>(
+        self,
+        dest: &'b R,
+    ) -> Result<Unit<'b, T>, (Self, AddUnitError)> {
+        // Should the destination panic, the value may have been dropped by it
+        // already. The unit is therefore kept from dropping it again, its
+        // slot would be leaked instead.
+        let unit = mem::ManuallyDrop::new(self);
+        // This is safe since the value is either moved to the destination, in
+        // which case the slot gets released without dropping it, or it is
+        // handed back and written to the slot again.
+        let value = unsafe { ptr::read(unit.as_ptr()) };
+        match dest.add_or_return(value) {
+            Ok(migrated) => {
+                unit.release();
+                Ok(migrated)
+            }
+            Err((value, error)) => {
+                let mut unit = mem::ManuallyDrop::into_inner(unit);
+                unsafe { ptr::write(unit.as_mut_ptr(), value) };
+                Err((unit, error))
+            }
+        }
+    }
+
+    /// Get the index of the `Rack` slot occupied by this unit.
+    ///
+    /// Values are stored in the first free slot of the `Rack`, starting from
+    /// the index `0`. The index stays the same for the whole life of the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let first = rack.must_add(1);
+    /// let second = rack.must_add(2);
+    ///
+    /// assert_eq!(first.index(), 0);
+    /// assert_eq!(second.index(), 1);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get a raw pointer to the value stored on the Rack.
+    ///
+    /// The pointer refers directly to the slot of the `Rack` and it stays the
+    /// same for the whole life of the `Unit`. It is valid only while the `Unit`
+    /// exists, once the `Unit` is dropped, the pointer is dangling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add(5);
+    /// let ptr = five.as_ptr();
+    ///
+    /// assert_eq!(unsafe { *ptr }, 5);
+    /// ```
+    pub fn as_ptr(&self) -> *const T {
+        self.slot.as_ptr()
+    }
+
+    /// Get a mutable raw pointer to the value stored on the Rack.
+    ///
+    /// The pointer refers directly to the slot of the `Rack` and it stays the
+    /// same for the whole life of the `Unit`. It is valid only while the `Unit`
+    /// exists, once the `Unit` is dropped, the pointer is dangling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// let ptr = number.as_mut_ptr();
+    /// unsafe { *ptr = 10 };
+    ///
+    /// assert_eq!(*number, 10);
+    /// ```
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.slot.as_ptr()
+    }
+
+    /// Check whether two units refer to the same slot.
+    ///
+    /// Unlike `==`, this compares identity of the stored values rather than
+    /// the values themselves, so it does not require `T` to implement
+    /// `PartialEq`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let a = rack.must_add(5);
+    /// let b = rack.must_add(5);
+    ///
+    /// assert!(Unit::ptr_eq(&a, &a));
+    /// assert!(!Unit::ptr_eq(&a, &b));
+    /// ```
+    pub fn ptr_eq(a: &Unit<'_, T>, b: &Unit<'_, T>) -> bool {
+        ptr::eq(a.as_ptr(), b.as_ptr())
+    }
+
+    /// Get a pinned reference to the stored value.
+    ///
+    /// # Safety
+    ///
+    /// The value does not move while the `Unit` exists, but the `Unit` itself
+    /// allows it to be moved out, e.g. through
+    /// [`get_mut`](struct.Unit.html#method.get_mut),
+    /// [`map`](struct.Unit.html#method.map),
+    /// [`swap`](struct.Unit.html#method.swap) or
+    /// [`migrate`](struct.Unit.html#method.migrate). Moreover, if the `Unit`
+    /// is forgotten, the memory of the rack can be reused once the rack goes
+    /// out of scope, without the value being dropped. Once this method is
+    /// called, the caller must not move the value anymore and must make sure
+    /// that the `Unit` gets dropped before the rack. Use
+    /// [`into_pin`](struct.Unit.html#method.into_pin) for a safe alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add(5);
+    /// let pinned = unsafe { five.as_pin() };
+    ///
+    /// assert_eq!(*pinned, 5);
+    /// ```
+    pub unsafe fn as_pin(&self) -> Pin<&T> {
+        Pin::new_unchecked(self.get_ref())
+    }
+
+    /// Get a pinned mutable reference to the stored value.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`as_pin`](struct.Unit.html#method.as_pin)
+    /// apply. Once this method is called, the caller must not move the value
+    /// anymore and must make sure that the `Unit` gets dropped before the
+    /// rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// let mut pinned = unsafe { number.as_pin_mut() };
+    /// pinned.set(10);
+    ///
+    /// assert_eq!(*number, 10);
+    /// ```
+    pub unsafe fn as_pin_mut(&mut self) -> Pin<&mut T> {
+        Pin::new_unchecked(self.get_mut())
+    }
+
+    /// Consume the `Unit` and return the stored value, freeing its slot on
+    /// the Rack.
+    ///
+    /// Note that `T` cannot implement `From<Unit<T>>` for any `T`, since Rust
+    /// does not allow foreign traits to be implemented for a bare type
+    /// parameter. This method is the conversion to use instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack1::new();
+    ///
+    /// let n: i32 = rack.must_add(5).into_inner();
+    /// assert_eq!(n, 5);
+    /// assert!(rack.add(6).is_ok());
+    /// ```
+    pub fn into_inner(self) -> T {
+        let unit = mem::ManuallyDrop::new(self);
+        // This is safe since the unit is not dropped, the value is read from
+        // the slot only once before the slot gets released.
+        let value = unsafe { ptr::read(unit.as_ptr()) };
+        unit.release();
+        value
+    }
+
+    /// Consume the `Unit` and free its slot on the Rack without dropping the
+    /// stored value.
+    ///
+    /// This works like `mem::forget` for the value, except that the slot can
+    /// be reused. The value is overwritten by the next one stored in the slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// struct Loud;
+    ///
+    /// impl Drop for Loud {
+    ///     fn drop(&mut self) {
+    ///         panic!("dropped");
+    ///     }
+    /// }
+    ///
+    /// let rack = Rack1::new();
+    ///
+    /// rack.must_add(Loud).detach();
+    /// assert!(rack.add(Loud).map(Unit::detach).is_ok());
+    /// ```
+    pub fn detach(self) {
+        mem::ManuallyDrop::new(self).release();
+    }
+
+    /// Attach a finalizer to the unit, which runs on the stored value right
+    /// before it gets dropped.
+    ///
+    /// This is useful for values holding handles to external resources which
+    /// need to be cleaned up besides what `Drop` of the type does. The
+    /// finalizer runs exactly once and it does not run if the value is taken
+    /// out using [`Finalized::into_inner`](struct.Finalized.html#method.into_inner).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::cell::Cell;
+    ///
+    /// let released = Cell::new(0);
+    /// let rack = Rack64::new();
+    ///
+    /// {
+    ///     let _handle = rack
+    ///         .must_add(3)
+    ///         .with_finalizer(|handle| released.set(*handle));
+    /// }
+    ///
+    /// assert_eq!(released.get(), 3);
+    /// ```
+    pub fn with_finalizer<F: FnOnce(&mut T)>(self, finalizer: F) -> Finalized<'a, T, F> {
+        Finalized::new(self, finalizer)
+    }
+
+    /// Convert the unit into one which never drops its value on its own.
+    ///
+    /// The value is dropped and its slot freed only once
+    /// [`ManagedUnit::drop_now`](struct.ManagedUnit.html#method.drop_now) is
+    /// called. Should the `ManagedUnit` get out of the scope before that, the
+    /// value is leaked together with its slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let mut five = rack.must_add(5).into_manually_managed();
+    /// *five += 1;
+    /// assert_eq!(*five, 6);
+    ///
+    /// five.drop_now();
+    /// ```
+    pub fn into_manually_managed(self) -> ManagedUnit<'a, T> {
+        ManagedUnit::new(self)
+    }
+
+    /// Consume the `Unit` and return a raw pointer to the stored value,
+    /// keeping the value on the Rack and its slot occupied.
+    ///
+    /// This is useful to hand the value over through FFI. The unit can be
+    /// rebuilt from the pointer using
+    /// [`ArrayRack::unit_from_raw`](struct.ArrayRack.html#method.unit_from_raw),
+    /// otherwise the value is never dropped and its slot is never freed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let ptr = rack.must_add(5).into_raw();
+    /// unsafe { *ptr += 1 };
+    ///
+    /// let six = unsafe { rack.unit_from_raw(ptr) };
+    /// assert_eq!(*six, 6);
+    /// ```
+    pub fn into_raw(self) -> *mut T {
+        // The slot stays claimed by the unit, until it is rebuilt.
+        mem::ManuallyDrop::new(self).slot.as_ptr()
+    }
+
+    /// Consume the `Unit` and return a mutable reference to the stored value,
+    /// leaving the value on the Rack.
+    ///
+    /// This works similarly to `Box::leak`. The value will never be dropped
+    /// and the slot it occupies will never be freed, it is lost for the rest of
+    /// the life of the `Rack`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let number: &mut i32 = rack.must_add(5).leak();
+    /// *number += 1;
+    ///
+    /// assert_eq!(*number, 6);
+    /// ```
+    pub fn leak(self) -> &'a mut T {
+        // The `Unit` is never dropped, therefore its slot is never released
+        // either and nobody else can ever access it.
+        let mut unit = mem::ManuallyDrop::new(self);
+        unsafe { &mut *unit.as_mut_ptr() }
+    }
+
+    // Mark the slot free, without dropping the value it holds.
+    fn release(&self) {
+        self.slot.release();
+        self.state.release(self.index);
+    }
+}
+
+impl<T> Unit<'static, T> {
+    /// Convert the `Unit` into a pinned one.
+    ///
+    /// The stored value keeps its address for the whole life of the `Unit`
+    /// and, once pinned, it cannot be moved out anymore. This is available
+    /// only for units of a rack which lives for the rest of the program.
+    /// Otherwise, a forgotten `Unit` would allow the memory of the rack to be
+    /// reused without dropping the value, breaking the guarantees of `Pin`.
+    ///
+    /// This allows racks to back self-referential types, such as futures.
+    ///
+    /// # Examples
+    ///
+    /// Poll a future stored on a rack:
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// use core::future::Future;
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake, Waker};
+    ///
+    /// struct Noop;
+    ///
+    /// impl Wake for Noop {
+    ///     fn wake(self: Arc<Self>) {}
+    /// }
+    ///
+    /// let rack: &'static _ = Box::leak(Box::new(Rack64::new()));
+    ///
+    /// let mut future = rack.must_add(async { 5 }).into_pin();
+    /// let waker = Waker::from(Arc::new(Noop));
+    /// let mut context = Context::from_waker(&waker);
+    ///
+    /// assert_eq!(future.as_mut().poll(&mut context), Poll::Ready(5));
+    /// ```
+    pub fn into_pin(self) -> Pin<Self> {
+        // This is safe since the value cannot be moved out of the pinned unit
+        // and the memory of the rack is never reused before it is dropped.
+        unsafe { Pin::new_unchecked(self) }
+    }
+}
+
+/// When the Unit gets out of scope, it will deallocate its space on the Rack
+/// and make sure that the stored value gets properly dropped.
+// Unit's value is carried inside `MaybeUninit`. `Drop` on `MaybeUninit` does
+// not do anything. Therefore, we have to implement the `Drop` trait, making
+// sure that a destructor is called on the carried payload. Only then the slot
+// is released.
+impl<T> Drop for Unit<'_, T> {
+    fn drop(&mut self) {
+        // The slot must not be released before the value is dropped, since the
+        // destructor may add new values to the same rack. It must be released
+        // even if the destructor panics though, otherwise it would be lost for
+        // good.
+        struct Release<'u, 'a, T>(&'u Unit<'a, T>);
+
+        impl<T> Drop for Release<'_, '_, T> {
+            fn drop(&mut self) {
+                self.0.release();
+            }
+        }
+
+        let _release = Release(self);
+        // This is safe since the Unit was the only owner of the stored data.
+        // The value is not accessed again, even if its destructor panics.
+        unsafe {
+            ptr::drop_in_place(self.slot.as_ptr());
+        }
+    }
+}
+
+impl<T> Deref for Unit<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get_ref()
+    }
+}
+
+impl<T> DerefMut for Unit<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.get_mut()
+    }
+}
+
+impl<T> AsRef<T> for Unit<'_, T> {
+    fn as_ref(&self) -> &T {
+        self.get_ref()
+    }
+}
+
+impl<T> AsMut<T> for Unit<'_, T> {
+    fn as_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+/// Arithmetic assignments operate on the stored value in place.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+/// let mut counter = rack.must_add(1);
+///
+/// counter += 5;
+/// counter -= 2;
+/// counter *= 3;
+///
+/// assert_eq!(*counter, 12);
+/// ```
+impl<T: AddAssign> AddAssign<T> for Unit<'_, T> {
+    fn add_assign(&mut self, rhs: T) {
+        *self.get_mut() += rhs;
+    }
+}
+
+impl<T: SubAssign> SubAssign<T> for Unit<'_, T> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self.get_mut() -= rhs;
+    }
+}
+
+impl<T: MulAssign> MulAssign<T> for Unit<'_, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self.get_mut() *= rhs;
+    }
+}
+
+/// Units are compared by the values they hold, not by the slots they occupy.
+/// Use [`Unit::ptr_eq`](struct.Unit.html#method.ptr_eq) to compare identity.
+impl<T: PartialEq> PartialEq for Unit<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_ref() == other.get_ref()
+    }
+}
+
+impl<T: Eq> Eq for Unit<'_, T> {}
+
+/// Units can be compared against bare values of the type they hold.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+/// assert!(rack.must_add(5) == 5);
+/// ```
+impl<T: PartialEq> PartialEq<T> for Unit<'_, T> {
+    fn eq(&self, other: &T) -> bool {
+        self.get_ref() == other
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Unit<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.get_ref().partial_cmp(other.get_ref())
+    }
+}
+
+impl<T: Ord> Ord for Unit<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_ref().cmp(other.get_ref())
+    }
+}
+
+/// The hash is computed from the stored value, consistently with `PartialEq`.
+impl<T: Hash> Hash for Unit<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_ref().hash(state);
+    }
+}
+
+// The output is kept the same as when it was derived for a unit holding a
+// borrowed cell of the rack.
+impl<T: fmt::Debug> fmt::Debug for Unit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // This is safe since only a shared reference is taken, while the unit
+        // is borrowed immutably.
+        let cell = unsafe { &*self.slot.value.get() };
+        f.debug_struct("Unit").field("cell", cell).finish()
+    }
+}
+
+/// Units are formatted the same way as the values they hold.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+/// let five = rack.must_add(5);
+///
+/// assert_eq!(format!("{}", five), "5");
+/// ```
+impl<T: fmt::Display> fmt::Display for Unit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.get_ref(), f)
+    }
+}
+
+/// A handle to a part of a value stored on the [`Rack`](trait.Rack.html),
+/// obtained through [`Unit::project`](struct.Unit.html#method.project).
+///
+/// It borrows the [`Unit`](struct.Unit.html) mutably and gives access only to
+/// the projected part of its value.
+pub struct Projection<'a, U> {
+    value: &'a mut U,
+}
+
+impl<U> Deref for Projection<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<U> DerefMut for Projection<'_, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+impl<U: fmt::Debug> fmt::Debug for Projection<'_, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Projection").field(&self.value).finish()
+    }
+}
+
+/// Formatting with `{:p}` prints the address of the stored value, as given
+/// by [`as_ptr`](struct.Unit.html#method.as_ptr).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+/// let five = rack.must_add(5);
+///
+/// assert_eq!(format!("{:p}", five), format!("{:p}", five.as_ptr()));
+/// ```
+impl<T> fmt::Pointer for Unit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn initialize_rack() {
+        let _rack: Rack2<_> = Rack2::<i32>::new();
+    }
+
+    #[test]
+    #[allow(clippy::declare_interior_mutable_const)]
+    fn initialize_rack_in_const_context() {
+        const RACK: Rack2<i32> = Rack2::new();
+        const EMPTY_RACK: Rack0<i32> = Rack0::new();
+
+        let rack = RACK;
+        let _unit = rack.must_add(10);
+
+        let empty_rack = EMPTY_RACK;
+        assert!(empty_rack.add(10).is_err());
+    }
+
+    #[test]
+    fn add_unit_to_rack() {
+        let rack = Rack2::<i32>::new();
 
         let _unit: Unit<_> = rack.must_add(10);
     }
 
     #[test]
-    fn get_immutable_reference_to_unit_value() {
-        let rack = Rack2::new();
+    fn get_immutable_reference_to_unit_value() {
+        let rack = Rack2::new();
+
+        let unit = rack.must_add(10);
+
+        assert_eq!(*unit.get_ref(), 10);
+    }
+
+    #[test]
+    fn get_multiple_immutable_references_to_unit_value() {
+        let rack = Rack2::new();
+
+        let unit = rack.must_add(10);
+
+        let ref_1 = unit.get_ref();
+        let ref_2 = unit.get_ref();
+
+        assert_eq!(ref_1, ref_2);
+    }
+
+    #[test]
+    fn get_mutable_reference_to_unit_value() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+
+        assert_eq!(*unit.get_mut(), 10);
+    }
+
+    #[test]
+    fn access_unit_value_by_dereferencing() {
+        let rack = Rack2::new();
+
+        let unit = rack.must_add(10);
+
+        assert_eq!(*unit, 10);
+    }
+
+    #[test]
+    fn pass_immutable_unit_by_deref_coercion() {
+        fn assert_ref_i32_eq_10(num: &i32) {
+            assert_eq!(*num, 10)
+        }
+
+        let rack = Rack2::new();
+
+        let unit = rack.must_add(10);
+
+        assert_ref_i32_eq_10(&unit)
+    }
+
+    #[test]
+    fn change_unit_value_through_mutable_reference() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+
+        let mut_ref = unit.get_mut();
+        *mut_ref = 20;
+
+        assert_eq!(*unit.get_ref(), 20);
+    }
+
+    #[test]
+    fn change_unit_struct_field_through_mutable_reference() {
+        struct Foo(i32);
+
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(Foo(10));
+
+        let mut_ref = unit.get_mut();
+        mut_ref.0 = 20;
+
+        assert_eq!(unit.get_ref().0, 20);
+    }
+
+    #[test]
+    fn change_unit_value_by_mutable_dereferencing() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+        *unit = 20;
+
+        assert_eq!(*unit.get_ref(), 20);
+    }
+
+    #[test]
+    fn drop_values_owned_by_rack_on_clear() {
+        use std::rc::Rc;
+
+        let value = Rc::new(1);
+        let mut rack = Rack4::new();
+
+        let key = rack.must_insert(Rc::clone(&value));
+        rack.must_add(Rc::clone(&value)).leak();
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        rack.clear();
+
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert_eq!(rack.get(key), None);
+        assert_eq!(rack.stats().used, 1);
+    }
+
+    #[test]
+    fn drop_all_values_at_the_end_of_rack_scope() {
+        use std::rc::Rc;
+
+        let value = Rc::new(1);
+
+        let index = Rack4::scope(|rack| {
+            let unit = rack.must_add(Rc::clone(&value));
+            rack.must_insert(Rc::clone(&value));
+            assert_eq!(Rc::strong_count(&value), 3);
+            unit.index()
+        });
+
+        assert_eq!(index, 0);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn update_unit_value_by_arithmetic_assignment() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+        unit += 5;
+        unit -= 3;
+        unit *= 2;
+
+        assert_eq!(*unit.get_ref(), 24);
+    }
+
+    #[test]
+    fn pass_mutable_unit_by_deref_coercion() {
+        fn assert_mut_ref_i32_editable(num: &mut i32) {
+            *num = 20;
+            assert_eq!(*num, 20)
+        }
+
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+
+        assert_mut_ref_i32_editable(&mut unit)
+    }
+
+    #[test]
+    fn map_unit_value_in_place() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+        let slot_before = unit.get_ref() as *const i32;
+
+        unit.map(|x| x * x);
+
+        assert_eq!(*unit, 100);
+        assert_eq!(unit.get_ref() as *const i32, slot_before);
+    }
+
+    #[test]
+    fn try_map_unit_value_in_place() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+
+        assert_eq!(unit.try_map(|x| Ok::<_, ()>(x * 2)), Ok(()));
+        assert_eq!(*unit, 20);
+    }
+
+    #[test]
+    fn keep_unit_value_when_try_map_fails() {
+        struct Foo(i32);
+
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(Foo(10));
+
+        assert_eq!(unit.try_map(|_| Err("failed")), Err("failed"));
+        assert_eq!(unit.0, 10);
+    }
+
+    #[test]
+    fn swap_values_of_units_from_different_racks() {
+        let rack_a = Rack2::new();
+        let rack_b = Rack2::new();
+
+        let mut unit_a = rack_a.must_add(10);
+        let mut unit_b = rack_b.must_add(20);
+        let slot_a = unit_a.get_ref() as *const i32;
+        let slot_b = unit_b.get_ref() as *const i32;
+
+        unit_a.swap(&mut unit_b);
+
+        assert_eq!(*unit_a, 20);
+        assert_eq!(*unit_b, 10);
+        assert_eq!(unit_a.get_ref() as *const i32, slot_a);
+        assert_eq!(unit_b.get_ref() as *const i32, slot_b);
+    }
+
+    #[test]
+    fn release_slot_even_if_value_panics_on_drop() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        struct PanicOnDrop;
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                panic!("PanicOnDrop dropped");
+            }
+        }
+
+        let rack = Rack1::new();
+
+        let unit = rack.must_add(Some(PanicOnDrop));
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(unit))).is_err());
+
+        let unit = rack.must_add(None);
+        assert_eq!(unit.index(), 0);
+    }
+
+    #[test]
+    fn mutate_field_through_projection() {
+        let rack = Rack2::new();
+        let mut unit = rack.must_add((10, 20));
+
+        {
+            let mut second = unit.project(|pair| &mut pair.1);
+            *second += 5;
+        }
+
+        assert_eq!(*unit, (10, 25));
+    }
+
+    #[test]
+    fn mutate_two_fields_at_once() {
+        let rack = Rack2::new();
+        let mut unit = rack.must_add((10, 20));
+
+        let (first, second) = unit.get_disjoint_mut(|pair| (&mut pair.0, &mut pair.1));
+        *first += *second;
+        *second += 1;
+
+        assert_eq!(*unit, (30, 21));
+    }
+
+    #[test]
+    fn format_unit_for_debugging() {
+        use std::format;
+
+        let rack = Rack2::new();
+        let unit = rack.must_add(10_i32);
+
+        // The format of `MaybeUninit` itself differs between Rust versions.
+        let cell = core::mem::MaybeUninit::<i32>::uninit();
+        assert_eq!(
+            format!("{:?}", unit),
+            format!("Unit {{ cell: {:?} }}", cell)
+        );
+    }
+
+    #[test]
+    fn format_addresses_of_distinct_units() {
+        use std::format;
+
+        let rack = Rack2::new();
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(20);
+
+        assert_ne!(format!("{:p}", unit1), format!("{:p}", unit2));
+    }
+
+    #[test]
+    fn duplicate_unit_into_another_rack() {
+        use std::string::String;
+
+        let source = Rack1::new();
+        let dest = Rack2::new();
+
+        let mut original = source.must_add(String::from("value"));
+        let mut duplicate = original.try_duplicate(&dest).unwrap();
+        original.push_str(" original");
+        duplicate.push_str(" duplicate");
+
+        assert_eq!(*original, "value original");
+        assert_eq!(*duplicate, "value duplicate");
+        assert_eq!(duplicate.index(), 0);
+    }
+
+    #[test]
+    fn fail_to_duplicate_unit_into_full_rack() {
+        let rack = Rack1::new();
+
+        let unit = rack.must_add(10);
+
+        assert_eq!(
+            unit.try_duplicate(&rack).unwrap_err(),
+            AddUnitError::FullRack { capacity: 1 }
+        );
+    }
+
+    #[test]
+    fn take_value_out_of_unit_without_dropping_it() {
+        use std::rc::Rc;
+
+        let value = Rc::new(1);
+        let rack = Rack1::new();
+
+        let unit = rack.must_add(Rc::clone(&value));
+        let taken = unit.into_inner();
+
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert!(Rc::ptr_eq(&taken, &value));
+        assert!(rack.add(Rc::new(2)).is_ok());
+    }
+
+    #[test]
+    fn free_slot_of_detached_unit_without_dropping_value() {
+        use std::cell::Cell;
+
+        struct CountDrops<'c>(&'c Cell<usize>);
+
+        impl Drop for CountDrops<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let rack = Rack1::new();
+
+        rack.must_add(CountDrops(&drops)).detach();
+        assert_eq!(drops.get(), 0);
+
+        let unit = rack.must_add(CountDrops(&drops));
+        assert_eq!(unit.index(), 0);
+        drop(unit);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn rebuild_unit_from_raw_pointer() {
+        let rack = Rack4::new();
+        let _unit1 = rack.must_add(10);
+
+        let ptr = rack.must_add(20).into_raw();
+        assert!(rack.add(30).is_ok());
+        let unit2 = unsafe { rack.unit_from_raw(ptr) };
+
+        assert_eq!(*unit2, 20);
+        assert_eq!(unit2.index(), 1);
+        drop(unit2);
+        assert_eq!(rack.must_add(40).index(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "The pointer does not refer to a unit of the rack")]
+    fn reject_raw_pointer_of_another_rack() {
+        let rack1 = Rack4::new();
+        let rack2 = Rack4::new();
+
+        let ptr = rack1.must_add(10).into_raw();
+        let _unit = unsafe { rack2.unit_from_raw(ptr) };
+    }
+
+    #[test]
+    fn clone_unit_into_its_own_rack_until_it_is_full() {
+        let rack = Rack4::new();
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+
+        let original = rack.must_add(30);
+        let mut clone = original.try_clone().unwrap();
+        *clone += 1;
+
+        assert_eq!(*original, 30);
+        assert_eq!(*clone, 31);
+        assert_eq!(clone.index(), 3);
+        assert_eq!(
+            original.try_clone().unwrap_err(),
+            AddUnitError::FullRack { capacity: 4 }
+        );
+    }
+
+    #[test]
+    fn clone_unit_into_rack_which_was_moved_before() {
+        let rack = Rack2::new();
+        let five = rack.must_add(5);
+        drop(five);
+
+        let racks = [rack];
+        let five = racks[0].must_add(5);
+        let copy = five.try_clone().unwrap();
+
+        assert_eq!(*copy, 5);
+        assert_eq!(copy.index(), 1);
+    }
+
+    #[test]
+    fn clone_unit_of_rack_slice_into_its_storage() {
+        let mut buffer = [const { Slot::new() }; 2];
+        let rack = RackSlice::new(&mut buffer);
+
+        let five = rack.must_add(5);
+        let copy = five.try_clone().unwrap();
+
+        assert_eq!(*copy, 5);
+        assert!(rack.add(6).is_err());
+    }
+
+    #[test]
+    fn duplicate_unit_into_its_own_rack_until_it_is_full() {
+        let rack = Rack4::new();
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+
+        let original = rack.must_add(30);
+        let mut clone = original.try_duplicate(&rack).unwrap();
+        *clone += 1;
+
+        assert_eq!(*original, 30);
+        assert_eq!(*clone, 31);
+        assert_eq!(clone.index(), 3);
+        assert_eq!(
+            original.try_duplicate(&rack).unwrap_err(),
+            AddUnitError::FullRack { capacity: 4 }
+        );
+    }
+
+    #[test]
+    fn report_allocations_and_releases_to_observer() {
+        use std::vec::Vec;
+
+        let rack = Rack4::new();
+        let unit1 = rack.must_add(10);
+
+        let mut events = Vec::new();
+        let unit3 = rack.observe(
+            |event| events.push(event),
+            || {
+                let unit2 = rack.must_add(20);
+                let unit3 = rack.must_add(30);
+                drop(unit2);
+                drop(unit1);
+                unit3
+            },
+        );
+        // Events outside of the call are not reported.
+        drop(unit3);
+
+        assert_eq!(
+            events,
+            [
+                RackEvent::Allocated(1),
+                RackEvent::Allocated(2),
+                RackEvent::Freed(1),
+                RackEvent::Freed(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_events_to_innermost_observer_only() {
+        use std::vec::Vec;
+
+        let rack = Rack4::new();
+
+        let mut outer = Vec::new();
+        let mut inner = Vec::new();
+        rack.observe(
+            |event| outer.push(event),
+            || {
+                rack.must_add(10).leak();
+                rack.observe(|event| inner.push(event), || rack.must_add(20).leak());
+                rack.must_add(30).leak();
+            },
+        );
+
+        assert_eq!(outer, [RackEvent::Allocated(0), RackEvent::Allocated(2)]);
+        assert_eq!(inner, [RackEvent::Allocated(1)]);
+    }
+
+    #[test]
+    fn unregister_observer_even_if_observed_call_panics() {
+        use std::cell::Cell;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let rack = Rack4::new();
+
+        let events = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            rack.observe(
+                |_| events.set(events.get() + 1),
+                || {
+                    rack.must_add(10).leak();
+                    panic!("observed call panicked");
+                },
+            )
+        }));
+        assert!(result.is_err());
+        rack.must_add(20).leak();
+
+        assert_eq!(events.get(), 1);
+    }
+
+    #[test]
+    fn add_values_while_iterating_over_rack() {
+        let rack = Rack4::new();
+        rack.must_insert(10);
+        rack.must_insert(20);
+
+        // Slots are never borrowed by iteration, so the rack is reported full
+        // only once all of its slots are occupied.
+        let mut units = [None, None];
+        for (value, unit) in rack.iter().zip(&mut units) {
+            *unit = Some(rack.add(value + 1).unwrap());
+        }
+
+        assert!(units.iter().all(Option::is_some));
+        assert_eq!(
+            rack.add(0).unwrap_err(),
+            AddUnitError::FullRack { capacity: 4 }
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn add_value_with_all_bytes_set_to_zero() {
+        let rack = Rack2::<[u8; 16]>::new();
+
+        let buffer = rack.add_zeroed().unwrap();
+        assert_eq!(*buffer, [0; 16]);
+
+        let _other = rack.add_zeroed().unwrap();
+        assert_eq!(
+            rack.add_zeroed().unwrap_err(),
+            AddUnitError::FullRack { capacity: 2 }
+        );
+    }
+
+    #[test]
+    fn drain_full_rack_into_bigger_one() {
+        let mut small = Rack2::new();
+        small.must_insert(1);
+        small.must_insert(2);
+
+        let big = Rack4::new();
+        let _unit = big.must_add(0);
+        small.drain_into(&big).unwrap();
+
+        assert!(small.values().next().is_none());
+        assert_eq!(small.first_free(), Some(0));
+        assert!(big.values().eq(&[1, 2]));
+    }
+
+    #[test]
+    fn keep_values_which_do_not_fit_into_drained_rack() {
+        let mut source = Rack4::new();
+        let unit = source.must_add(0);
+        source.must_insert(1);
+        source.must_insert(2);
+        drop(unit);
+
+        let target = Rack1::new();
+        assert_eq!(
+            source.drain_into(&target),
+            Err(AddUnitError::FullRack { capacity: 1 })
+        );
+
+        assert!(source.values().eq(&[2]));
+        assert!(target.values().eq(&[1]));
+    }
+
+    #[test]
+    fn report_capacity_and_available_memory_in_bytes() {
+        let mut rack = Rack4::<[u8; 8]>::new();
+        // Each slot of eight bytes takes another four for its tag, rounded up
+        // to a multiple of four.
+        let stride = 8 + 4;
+        assert_eq!(rack.capacity_bytes(), 4 * stride);
+        assert_eq!(rack.available_bytes(), 4 * stride);
+
+        let unit = rack.must_add([0; 8]);
+        rack.must_insert([1; 8]);
+        assert_eq!(rack.available_bytes(), 2 * stride);
+
+        drop(unit);
+        assert_eq!(rack.available_bytes(), 3 * stride);
+
+        rack.retain(|_| false);
+        assert_eq!(rack.available_bytes(), rack.capacity_bytes());
+    }
+
+    #[test]
+    fn summarize_occupancy_consistently_with_other_methods() {
+        let rack = Rack4::<[u8; 8]>::new();
+        let _unit = rack.must_add([0; 8]);
+        rack.must_insert([1; 8]);
+
+        let stats = rack.stats();
+
+        assert_eq!(stats.capacity, rack.capacity());
+        assert_eq!(stats.used, 2);
+        assert_eq!(stats.free, 2);
+        assert_eq!(stats.bytes_capacity, rack.capacity_bytes());
+        assert_eq!(stats.bytes_used, stats.used * rack.value_stride());
+        assert_eq!(
+            stats.bytes_capacity - stats.bytes_used,
+            rack.available_bytes()
+        );
+    }
+
+    #[test]
+    fn measure_distance_between_values_of_adjacent_slots() {
+        fn check<T: Default>() {
+            let rack = ArrayRack::<T, 2>::new();
+            let a = rack.must_add(T::default());
+            let b = rack.must_add(T::default());
+            assert_eq!(
+                b.as_ptr() as usize - a.as_ptr() as usize,
+                rack.value_stride()
+            );
+        }
+
+        check::<u8>();
+        check::<u64>();
+        check::<[u8; 5]>();
+        check::<(u16, u32)>();
+    }
+
+    #[test]
+    fn report_slot_occupied_only_while_it_holds_value() {
+        let mut rack = Rack4::new();
+        assert!((0..5).all(|index| !rack.is_index_occupied(index)));
+
+        let unit = rack.must_add(1);
+        let key = rack.insert(2).unwrap();
+        assert!(rack.is_index_occupied(unit.index()));
+        assert!(rack.is_index_occupied(key.index()));
+        assert!(!rack.is_index_occupied(2));
+
+        let index = unit.index();
+        drop(unit);
+        assert!(!rack.is_index_occupied(index));
+        assert!(rack.is_index_occupied(key.index()));
+
+        rack.remove(key);
+        assert!(!rack.is_index_occupied(key.index()));
+    }
+
+    #[test]
+    fn predict_slot_of_next_added_value() {
+        fn check<P: SlotPolicy>() {
+            let rack = ArrayRack::<_, 4, P>::new();
+            let units: [Unit<i32>; 3] = core::array::from_fn(|i| {
+                let index = rack.first_free();
+                let unit = rack.must_add(i as i32);
+                assert_eq!(index, Some(unit.index()));
+                unit
+            });
+            let [unit1, _unit2, unit3] = units;
+            drop(unit3);
+            drop(unit1);
+
+            for value in 3..5 {
+                let index = rack.first_free();
+                let unit = rack.must_add(value);
+                assert_eq!(index, Some(unit.index()));
+                core::mem::forget(unit);
+            }
+            assert_eq!(rack.first_free(), Some(3));
+        }
+
+        check::<FirstFit>();
+        check::<MostRecentlyFreed>();
+    }
+
+    #[test]
+    fn find_first_value_satisfying_predicate() {
+        let rack = Rack4::new();
+        for value in [1, 2, 4, 5] {
+            rack.must_insert(value);
+        }
+
+        assert_eq!(rack.find(|value| *value > 3), Some(&4));
+        assert_eq!(rack.find(|value| *value > 5), None);
+    }
+
+    #[test]
+    fn locate_slot_of_first_value_satisfying_predicate() {
+        let mut rack = Rack4::new();
+        let one = rack.must_insert(1);
+        rack.must_insert(2);
+        rack.remove(one);
+        let four = rack.must_insert(4);
+
+        assert_eq!(rack.position(|value| *value == 4), Some(four.index()));
+        assert_eq!(rack.position(|value| *value == 4), Some(0));
+        assert_eq!(rack.position(|value| *value == 1), None);
+    }
+
+    #[test]
+    fn skip_values_held_by_units_when_finding_value() {
+        let rack = Rack4::new();
+        let mut unit = rack.must_add(4);
+        let five = rack.must_insert(5);
+
+        assert_eq!(rack.find(|value| *value > 3), Some(&5));
+        assert_eq!(rack.find(|value| *value == 4), None);
+        assert_eq!(rack.position(|value| *value > 3), Some(five.index()));
+        assert_eq!(rack.position(|value| *value == 4), None);
+        *unit += 1;
+    }
+
+    #[test]
+    fn populate_remaining_slots_with_default_values() {
+        let rack = Rack4::<i32>::new();
+        let unit = rack.must_add(5);
+
+        let defaults = rack.fill().collect::<std::vec::Vec<_>>();
+
+        assert_eq!(defaults.len(), 3);
+        assert!(defaults.iter().all(|unit| *unit == 0));
+        assert_eq!(unit, 5);
+        assert_eq!(rack.first_free(), None);
+    }
+
+    #[test]
+    fn add_all_values_of_array_or_none_of_them() {
+        let rack = Rack4::new();
+        let units = rack.add_array([1, 2, 3]).unwrap();
+        assert_eq!(units, [1, 2, 3]);
+        assert_eq!(rack.first_free(), Some(3));
+
+        let rack = Rack2::new();
+        assert_eq!(
+            rack.add_array([1, 2, 3]).err(),
+            Some(AddUnitError::FullRack { capacity: 2 })
+        );
+        assert_eq!(rack.first_free(), Some(0));
+    }
+
+    #[test]
+    fn release_slots_of_partially_added_array_on_fallback() {
+        let primary = Rack1::new();
+        let fallback = Rack1::new();
+        let rack = ChainRack::new(&primary, &fallback);
+
+        assert!(rack.add_array([1, 2, 3]).is_err());
+        assert!(primary.add(1).is_ok());
+        assert!(fallback.add(2).is_ok());
+    }
+
+    #[test]
+    fn tell_units_of_different_racks_apart() {
+        let mut buffer = [const { Slot::new() }; 2];
+        let rack_a = Rack2::new();
+        let rack_b = RackSlice::new(&mut buffer);
+
+        let unit_a = rack_a.must_add(10);
+        let unit_b = rack_b.must_add(20);
+
+        assert!(rack_a.owns(&unit_a));
+        assert!(!rack_b.owns(&unit_a));
+        assert!(rack_b.owns(&unit_b));
+        assert!(!rack_a.owns(&unit_b));
+    }
+
+    #[test]
+    fn migrate_unit_to_larger_rack() {
+        let small = Rack2::new();
+        let large = Rack4::new();
+
+        let unit1 = small.must_add(10);
+        let _unit2 = small.must_add(20);
+        let _unit3 = large.must_add(30);
+
+        let unit1 = unit1.migrate(&large).unwrap();
+
+        assert_eq!(*unit1, 10);
+        assert_eq!(unit1.index(), 1);
+        assert_eq!(small.must_add(40).index(), 0);
+    }
+
+    #[test]
+    fn return_unit_when_migration_fails() {
+        use std::string::String;
+
+        let source = Rack2::new();
+        let dest = Rack1::new();
+        let _occupant = dest.must_add(String::from("occupant"));
+
+        let unit = source.must_add(String::from("value"));
+        let (unit, error) = unit.migrate(&dest).unwrap_err();
+
+        assert_eq!(error, AddUnitError::FullRack { capacity: 1 });
+        assert_eq!(*unit, "value");
+        assert_eq!(unit.index(), 0);
+        assert_eq!(source.must_add(String::new()).index(), 1);
+    }
+
+    #[test]
+    fn drop_migrated_value_exactly_once() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let source = Rack1::new();
+        let dest = Rack1::new();
+
+        let unit = source.must_add(Rc::clone(&counter));
+        let unit = unit.migrate(&dest).unwrap();
+        assert_eq!(Rc::strong_count(&counter), 2);
+
+        let unit = unit.migrate(&dest).unwrap_err().0;
+        assert_eq!(Rc::strong_count(&counter), 2);
+
+        drop(unit);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn keep_pinned_self_referential_value_in_place() {
+        use core::marker::PhantomPinned;
+        use std::boxed::Box;
+
+        // A value pointing to its own field, like a future holding a
+        // reference across an await point.
+        struct SelfReferential {
+            value: i32,
+            pointer: *const i32,
+            _pinned: PhantomPinned,
+        }
+
+        impl SelfReferential {
+            fn init(self: Pin<&mut Self>) {
+                // This is safe since the value is not moved out.
+                let this = unsafe { self.get_unchecked_mut() };
+                this.pointer = &this.value;
+            }
+
+            fn get(self: Pin<&Self>) -> i32 {
+                // This is safe since the value was pinned when the pointer
+                // was set.
+                unsafe { *self.pointer }
+            }
+        }
+
+        let rack: &'static Rack4<SelfReferential> = Box::leak(Box::new(Rack4::new()));
+        let mut unit = rack
+            .must_add(SelfReferential {
+                value: 5,
+                pointer: ptr::null(),
+                _pinned: PhantomPinned,
+            })
+            .into_pin();
+
+        unit.as_mut().init();
+        let _other = rack.must_add(SelfReferential {
+            value: 6,
+            pointer: ptr::null(),
+            _pinned: PhantomPinned,
+        });
+
+        assert_eq!(unit.as_ref().get(), 5);
+    }
+
+    #[test]
+    fn get_index_of_occupied_slot() {
+        let rack = Rack4::new();
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(20);
+        let unit3 = rack.must_add(30);
+
+        assert_eq!(unit1.index(), 0);
+        assert_eq!(unit2.index(), 1);
+        assert_eq!(unit3.index(), 2);
+    }
+
+    #[test]
+    fn keep_raw_pointer_to_unit_value_stable() {
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+
+        let ptr_before = unit.as_ptr();
+        assert_eq!(*unit.get_ref(), 10);
+        let ptr_after = unit.as_ptr();
+
+        assert_eq!(ptr_before, ptr_after);
+        assert_eq!(unit.as_mut_ptr() as *const i32, ptr_before);
+    }
+
+    #[test]
+    fn compare_identity_of_units() {
+        let rack = Rack2::new();
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(10);
+
+        assert!(Unit::ptr_eq(&unit1, &unit1));
+        assert!(!Unit::ptr_eq(&unit1, &unit2));
+    }
+
+    #[test]
+    fn keep_leaked_unit_value_alive() {
+        use core::cell::Cell;
+
+        struct Foo<'a>(&'a Cell<i32>);
+
+        impl Drop for Foo<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let rack = Rack2::new();
+
+        {
+            let leaked = rack.must_add(Foo(&drops)).leak();
+            assert_eq!(leaked.0.get(), 0);
+        }
+
+        assert_eq!(drops.get(), 0);
+        let _unit = rack.must_add(Foo(&drops));
+        assert!(rack.add(Foo(&drops)).is_err());
+    }
+
+    #[test]
+    fn bump_generation_of_slot_reused_by_added_value() {
+        let mut rack = Rack2::new();
+        let (first, first_generation) = rack.add_with_generation(1).unwrap();
+        let index = first.index();
+        drop(first);
+
+        let (second, second_generation) = rack.add_with_generation(2).unwrap();
+        assert_eq!(second.index(), index);
+        assert_eq!(second_generation, first_generation + 1);
+        drop(second);
+
+        // Values owned by the rack bump the generation as well.
+        let key = rack.must_insert(3);
+        rack.remove(key);
+        let (third, third_generation) = rack.add_with_generation(4).unwrap();
+        assert_eq!(third.index(), index);
+        assert_eq!(third_generation, second_generation + 2);
+    }
+
+    #[test]
+    fn keep_unit_three_words_large() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<Unit<u8>>(), 3 * size_of::<usize>());
+        assert_eq!(size_of::<Unit<[u64; 64]>>(), 3 * size_of::<usize>());
+    }
+
+    #[test]
+    fn compare_units_by_value() {
+        let rack = Rack4::new();
+
+        let unit1 = rack.must_add(5);
+        let unit2 = rack.must_add(5);
+        let unit3 = rack.must_add(6);
+
+        assert_eq!(unit1, unit2);
+        assert_ne!(unit1, unit3);
+    }
+
+    #[test]
+    fn compare_unit_with_bare_value() {
+        let rack = Rack4::new();
+
+        let unit = rack.must_add(5);
+
+        assert_eq!(unit, 5);
+        assert_ne!(unit, 6);
+    }
+
+    #[test]
+    fn sort_units_by_value() {
+        let rack = Rack4::new();
+
+        let mut units = [rack.must_add(3), rack.must_add(1), rack.must_add(2)];
+        units.sort();
+
+        assert_eq!(*units[0], 1);
+        assert_eq!(*units[1], 2);
+        assert_eq!(*units[2], 3);
+        assert!(units[0] < units[1]);
+    }
+
+    #[test]
+    fn hash_units_by_value() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let rack = Rack4::new();
+
+        let unit1 = rack.must_add(5);
+        let unit2 = rack.must_add(5);
+
+        assert_eq!(hash_of(&unit1), hash_of(&unit2));
+        assert_eq!(hash_of(&unit1), hash_of(&5));
+    }
+
+    #[test]
+    fn pass_unit_as_generic_reference() {
+        fn assert_as_ref_eq_10(num: impl AsRef<i32>) {
+            assert_eq!(*num.as_ref(), 10)
+        }
+
+        fn set_as_mut_to_20(mut num: impl AsMut<i32>) {
+            *num.as_mut() = 20;
+        }
+
+        let rack = Rack2::new();
+
+        let mut unit = rack.must_add(10);
+        assert_as_ref_eq_10(&unit);
+
+        set_as_mut_to_20(&mut unit);
+        assert_eq!(*unit, 20);
+    }
+
+    #[test]
+    fn add_units_through_rack_trait_object() {
+        fn add_all(rack: &dyn Rack<i32>) -> usize {
+            let units: [_; 5] = core::array::from_fn(|i| rack.add(i as i32).ok());
+            units.iter().flatten().count()
+        }
+
+        let array = Rack2::new();
+        let mut buffer = [const { Slot::new() }; 2];
+        let slice = RackSlice::new(&mut buffer);
+        let aligned = AlignedRack::<_, 16, 2>::new();
+        assert_eq!(add_all(&array), 2);
+        assert_eq!(add_all(&slice), 2);
+        assert_eq!(add_all(&aligned), 2);
+
+        let primary: &dyn Rack<i32> = &array;
+        let fallback: &dyn Rack<i32> = &slice;
+        let chain = ChainRack::new(primary, fallback);
+        assert_eq!(add_all(&chain), 4);
+    }
+
+    #[test]
+    fn accept_up_to_the_limit() {
+        let rack = Rack2::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+    }
+
+    #[test]
+    #[should_panic(expected = "The rack is full")]
+    fn rejects_over_the_limit_with_panic_on_must_add() {
+        let rack = Rack2::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+        let _unit3 = rack.must_add(30);
+    }
+
+    #[test]
+    fn rejects_over_the_limit_with_error_on_add() {
+        let rack = Rack2::new();
+
+        let _unit1 = rack.add(10).unwrap();
+        let _unit2 = rack.add(20).unwrap();
+
+        // Allow unreachable patterns in case more error types are added to
+        // AddUnitError, so the match would panic on the default arm.
+        #[allow(unreachable_patterns)]
+        match rack
+            .add(30)
+            .expect_err("Add to full stack should return an error")
+        {
+            AddUnitError::FullRack { capacity: 2 } => (),
+            _ => panic!("Adding over limit returned unexpected error"),
+        };
+    }
+
+    #[test]
+    fn accept_up_to_an_arbitrary_limit() {
+        let rack = ArrayRack::<i32, 3>::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+        let _unit3 = rack.must_add(30);
+
+        assert!(rack.add(40).is_err());
+    }
+
+    #[test]
+    fn accept_up_to_the_limit_of_user_defined_rack() {
+        define_rack!(Rack3, 3);
+
+        let rack = Rack3::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+        let _unit3 = rack.must_add(30);
+
+        assert!(rack.add(40).is_err());
+    }
+
+    #[test]
+    fn store_units_in_rack_slice_over_stack_buffer() {
+        let mut buffer = [const { Slot::new() }; 2];
+        let rack = RackSlice::new(&mut buffer);
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(20);
+
+        assert_eq!(*unit1, 10);
+        assert_eq!(*unit2, 20);
+        assert!(rack.add(30).is_err());
+    }
+
+    #[test]
+    fn store_units_in_rack_slice_over_static_buffer() {
+        static mut BUFFER: [Slot<i32>; 2] = [const { Slot::new() }; 2];
+
+        // This is safe since this is the only place accessing the buffer.
+        let rack = RackSlice::new(unsafe { &mut *ptr::addr_of_mut!(BUFFER) });
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(20);
+
+        assert_eq!(*unit1, 10);
+        assert_eq!(*unit2, 20);
+        assert!(rack.add(30).is_err());
+    }
+
+    #[test]
+    fn compare_errors_of_full_racks() {
+        let rack = Rack1::new();
+        let _unit = rack.must_add(10);
+
+        let error = rack.add(20).unwrap_err();
+        let copy = error;
+
+        assert_eq!(error, AddUnitError::FullRack { capacity: 1 });
+        assert_eq!(copy, error);
+        assert_ne!(error, Rack0::new().add(20).unwrap_err());
+    }
+
+    #[test]
+    fn mention_capacity_of_full_rack_in_error_message() {
+        use std::string::ToString;
+
+        let rack = Rack64::new();
+        let _units = rack.fill().collect::<std::vec::Vec<_>>();
+
+        let error = rack.add(0).unwrap_err();
+
+        assert_eq!(error.to_string(), "the rack is full (capacity 64)");
+    }
+
+    #[test]
+    fn reject_everything_with_error_on_add_to_zero_capacity_rack() {
+        let rack = Rack0::new();
+
+        assert!(rack.add(10).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "The rack is full")]
+    fn reject_everything_with_panic_on_must_add_to_zero_capacity_rack() {
+        let rack = Rack0::new();
+
+        let _unit = rack.must_add(10);
+    }
+
+    #[test]
+    fn accept_more_units_once_old_ones_get_out_of_scope() {
+        let rack = Rack2::new();
+
+        let _unit1 = rack.must_add(10);
+        {
+            let _unit2 = rack.must_add(20);
+        }
+        let _unit3 = rack.must_add(30);
+    }
+
+    #[test]
+    fn reuse_released_slots_starting_from_the_first_one() {
+        let rack = Rack4::new();
+
+        let unit1 = rack.must_add(10);
+        let unit2 = rack.must_add(20);
+        let _unit3 = rack.must_add(30);
+        drop(unit1);
+        drop(unit2);
+
+        let unit4 = rack.must_add(40);
+        let unit5 = rack.must_add(50);
+
+        assert_eq!(unit4.index(), 0);
+        assert_eq!(unit5.index(), 1);
+    }
+
+    #[test]
+    fn add_clone_independent_of_the_original() {
+        use std::string::String;
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Named {
+            name: String,
+        }
+
+        let rack = Rack4::new();
+        let original = Named {
+            name: String::from("original"),
+        };
+
+        let mut clone = rack.must_add_cloned(&original);
+        clone.name.push_str(" clone");
+
+        assert_eq!(original.name, "original");
+        assert_eq!(clone.name, "original clone");
+    }
+
+    #[test]
+    fn never_clone_value_when_rack_is_full() {
+        use core::cell::Cell;
+
+        struct CountClones<'a>(&'a Cell<usize>);
+
+        impl Clone for CountClones<'_> {
+            fn clone(&self) -> Self {
+                self.0.set(self.0.get() + 1);
+                Self(self.0)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let rack = Rack1::new();
+        let value = CountClones(&clones);
+
+        let _unit = rack.must_add_cloned(&value);
+        assert!(rack.add_cloned(&value).is_err());
+        assert!(Rack0::new().add_cloned(&value).is_err());
+
+        assert_eq!(clones.get(), 1);
+    }
+
+    #[test]
+    fn reuse_slot_of_the_last_dropped_unit() {
+        let rack = Rack4::new();
+
+        let unit_a = rack.must_add(10);
+        let unit_b = rack.must_add(20);
+        let index_b = unit_b.index();
+        drop(unit_b);
+
+        let unit_c = rack.must_add(30);
+
+        assert_eq!(unit_a.index(), 0);
+        assert_eq!(unit_c.index(), index_b);
+    }
+
+    #[test]
+    fn reuse_most_recently_freed_slot() {
+        let rack = ArrayRack::<_, 4, MostRecentlyFreed>::new();
 
-        let unit = rack.must_add(10);
+        let unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+        let unit3 = rack.must_add(30);
+        drop(unit1);
+        drop(unit3);
 
-        assert_eq!(*unit.get_ref(), 10);
+        let unit4 = rack.must_add(40);
+        let unit5 = rack.must_add(50);
+        let unit6 = rack.must_add(60);
+
+        assert_eq!(unit4.index(), 2);
+        assert_eq!(unit5.index(), 0);
+        assert_eq!(unit6.index(), 3);
     }
 
     #[test]
-    fn get_multiple_immutable_references_to_unit_value() {
-        let rack = Rack2::new();
+    fn never_hand_out_occupied_slot_with_most_recently_freed_policy() {
+        const CAPACITY: usize = 100;
 
-        let unit = rack.must_add(10);
+        let rack = ArrayRack::<usize, CAPACITY, MostRecentlyFreed>::new();
+        let mut units: [Option<Unit<usize>>; CAPACITY] = [const { None }; CAPACITY];
 
-        let ref_1 = unit.get_ref();
-        let ref_2 = unit.get_ref();
+        let mut seed: u32 = 42;
+        for value in 0..1000 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let position = (seed >> 16) as usize % CAPACITY;
+            match units[position].take() {
+                Some(unit) => drop(unit),
+                None => units[position] = Some(rack.must_add(value)),
+            }
 
-        assert_eq!(ref_1, ref_2);
+            let mut occupied = [false; CAPACITY];
+            for unit in units.iter().flatten() {
+                assert!(!occupied[unit.index()]);
+                occupied[unit.index()] = true;
+            }
+            assert_bitmap_in_sync(&rack);
+        }
     }
 
     #[test]
-    fn get_mutable_reference_to_unit_value() {
-        let rack = Rack2::new();
+    fn never_hand_out_occupied_slot_with_interleaved_add_and_drop() {
+        const CAPACITY: usize = 8;
 
-        let mut unit = rack.must_add(10);
+        let rack = ArrayRack::<usize, CAPACITY>::new();
+        let mut units: [Option<Unit<usize>>; CAPACITY] = Default::default();
 
-        assert_eq!(*unit.get_mut(), 10);
+        // A simple linear congruential generator keeps the test deterministic.
+        let mut seed: u32 = 42;
+        for value in 0..1000 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let position = (seed >> 16) as usize % CAPACITY;
+            match units[position].take() {
+                Some(unit) => drop(unit),
+                None => units[position] = Some(rack.must_add(value)),
+            }
+
+            let mut occupied = [false; CAPACITY];
+            for unit in units.iter().flatten() {
+                assert!(!occupied[unit.index()]);
+                occupied[unit.index()] = true;
+            }
+            if occupied.iter().all(|o| *o) {
+                assert!(rack.add(value).is_err());
+            }
+        }
+    }
+
+    // Check that each group of slots is marked full in the bitmap exactly when
+    // all of its slots are occupied.
+    fn assert_bitmap_in_sync<T, const N: usize, P>(rack: &ArrayRack<T, N, P>) {
+        for index in 0..N {
+            let occupied = rack
+                .state
+                .bitmap
+                .group_of(index, N)
+                .all(|i| !rack.slots[i].is_free());
+            assert_eq!(rack.state.bitmap.is_full(index), occupied, "slot {}", index);
+        }
     }
 
     #[test]
-    fn access_unit_value_by_dereferencing() {
-        let rack = Rack2::new();
+    fn keep_bitmap_in_sync_with_occupancy_after_nested_scopes() {
+        let rack = Rack4::new();
 
-        let unit = rack.must_add(10);
+        let _unit1 = rack.must_add(10);
+        assert_bitmap_in_sync(&rack);
+        {
+            let _unit2 = rack.must_add(20);
+            {
+                let _unit3 = rack.must_add(30);
+                let _unit4 = rack.must_add(40);
+                assert_bitmap_in_sync(&rack);
+                assert!(rack.add(50).is_err());
+            }
+            assert_bitmap_in_sync(&rack);
+            let _unit5 = rack.must_add(50);
+            assert_bitmap_in_sync(&rack);
+        }
+        assert_bitmap_in_sync(&rack);
 
-        assert_eq!(*unit, 10);
+        let unit6 = rack.must_add(60);
+        assert_eq!(unit6.index(), 1);
     }
 
     #[test]
-    fn pass_immutable_unit_by_deref_coercion() {
-        fn assert_ref_i32_eq_10(num: &i32) {
-            assert_eq!(*num, 10)
+    fn keep_bitmap_in_sync_with_occupancy_of_grouped_slots() {
+        // With more than 64 slots, each bit of the bitmap covers a group of
+        // three slots.
+        let rack = ArrayRack::<usize, 130>::new();
+        let mut units: [Option<Unit<usize>>; 130] = [const { None }; 130];
+
+        for (i, unit) in units.iter_mut().enumerate() {
+            *unit = Some(rack.must_add(i));
         }
+        assert_bitmap_in_sync(&rack);
+        assert!(rack.add(130).is_err());
 
-        let rack = Rack2::new();
+        for i in (0..130).step_by(7) {
+            units[i] = None;
+            assert_bitmap_in_sync(&rack);
+        }
+        for i in (0..130).step_by(7) {
+            let unit = rack.must_add(i);
+            assert_eq!(unit.index(), i);
+            units[i] = Some(unit);
+            assert_bitmap_in_sync(&rack);
+        }
+        assert!(rack.add(130).is_err());
+    }
+
+    #[test]
+    fn insert_and_remove_values_by_key() {
+        let mut rack = Rack4::new();
+
+        let key1 = rack.must_insert(10);
+        let key2 = rack.must_insert(20);
+        *rack.get_mut(key2).unwrap() += 1;
+
+        assert_eq!(rack.get(key1), Some(&10));
+        assert_eq!(rack.remove(key2), Some(21));
+        assert_eq!(rack.get(key2), None);
+        assert_eq!(rack.get(key1), Some(&10));
+    }
+
+    #[test]
+    fn invalidate_key_of_removed_value_once_slot_is_reused() {
+        let mut rack = Rack1::new();
+
+        let old = rack.must_insert(10);
+        rack.remove(old);
+        let new = rack.must_insert(20);
+
+        assert_eq!(old.index(), new.index());
+        assert_eq!(rack.get(old), None);
+        assert_eq!(rack.get_mut(old), None);
+        assert_eq!(rack.remove(old), None);
+        assert_eq!(rack.get(new), Some(&20));
+    }
+
+    #[test]
+    fn invalidate_key_once_slot_was_reused_by_unit() {
+        let mut rack = Rack1::new();
+
+        let old = rack.must_insert(10);
+        rack.remove(old);
+        let unit = rack.must_add(20);
+        assert_eq!(unit.index(), old.index());
+        drop(unit);
+        let new = rack.must_insert(30);
+
+        assert_eq!(new.generation(), old.generation() + 2);
+        assert_eq!(rack.get(old), None);
+        assert_eq!(rack.get(new), Some(&30));
+    }
+
+    #[test]
+    fn get_value_owned_by_rack_by_index() {
+        let mut rack = Rack4::new();
 
+        let key = rack.must_insert(10);
+        assert_eq!(rack.get_at(0), Some(&10));
+        *rack.get_at_mut(0).unwrap() += 1;
+        assert_eq!(rack.get_at(0), Some(&11));
+
+        rack.remove(key);
+        assert_eq!(rack.get_at(0), None);
+        assert_eq!(rack.get_at_mut(0), None);
+        assert_eq!(rack.get_at(4), None);
+    }
+
+    #[test]
+    fn never_get_value_owned_by_unit_by_index() {
+        let mut rack = Rack4::new();
+
+        let key = rack.must_insert(5);
         let unit = rack.must_add(10);
 
-        assert_ref_i32_eq_10(&unit)
+        assert_eq!(rack.get_at(key.index()), Some(&5));
+        assert_eq!(rack.get_at(unit.index()), None);
+        assert!(rack.is_index_occupied(unit.index()));
+
+        let index = unit.index();
+        unit.leak();
+        assert_eq!(rack.get_at_mut(index), None);
     }
 
     #[test]
-    fn change_unit_value_through_mutable_reference() {
-        let rack = Rack2::new();
+    fn iterate_over_occupied_slots_with_their_indexes() {
+        let mut rack = Rack4::new();
 
-        let mut unit = rack.must_add(10);
+        rack.must_insert(10);
+        let key = rack.must_insert(20);
+        rack.must_insert(30);
+        rack.remove(key);
+        let unit = rack.must_add(40);
 
-        let mut_ref = unit.get_mut();
-        *mut_ref = 20;
+        let mut iter = rack.iter_occupied();
+        assert_eq!(iter.next(), Some((0, &10)));
+        assert_eq!(iter.next(), Some((2, &30)));
+        assert_eq!(iter.next(), None);
 
-        assert_eq!(*unit.get_ref(), 20);
+        assert_eq!(unit.index(), 1);
+        assert!(rack.is_index_occupied(unit.index()));
     }
 
     #[test]
-    fn change_unit_struct_field_through_mutable_reference() {
-        struct Foo(i32);
+    fn check_whether_rack_contains_value() {
+        let mut rack = Rack4::new();
 
-        let rack = Rack2::new();
+        let key = rack.must_insert(5);
+        let unit = rack.must_add(6);
 
-        let mut unit = rack.must_add(Foo(10));
+        assert!(rack.contains(&5));
+        assert!(!rack.contains(&6));
+        assert_eq!(*unit, 6);
 
-        let mut_ref = unit.get_mut();
-        mut_ref.0 = 20;
+        drop(unit);
+        rack.remove(key);
 
-        assert_eq!(unit.get_ref().0, 20);
+        assert!(!rack.contains(&5));
     }
 
     #[test]
-    fn change_unit_value_by_mutable_dereferencing() {
-        let rack = Rack2::new();
+    fn sum_values_owned_by_rack() {
+        let mut buffer = [const { Slot::new() }; 4];
+        let rack = RackSlice::new(&mut buffer);
 
-        let mut unit = rack.must_add(10);
-        *unit = 20;
+        rack.must_insert(10);
+        rack.must_insert(20);
+        let _unit = rack.must_add(30);
 
-        assert_eq!(*unit.get_ref(), 20);
+        let mut sum = 0;
+        for value in &rack {
+            sum += value;
+        }
+        assert_eq!(sum, 30);
     }
 
     #[test]
-    fn pass_mutable_unit_by_deref_coercion() {
-        fn assert_mut_ref_i32_editable(num: &mut i32) {
-            *num = 20;
-            assert_eq!(*num, 20)
+    fn aggregate_values_owned_by_rack() {
+        let rack = Rack4::new();
+        assert_eq!(rack.min(), None);
+        assert_eq!(rack.max(), None);
+        assert_eq!(rack.sum(), 0);
+
+        for value in [3, 1, 4, 1] {
+            rack.must_insert(value);
         }
 
-        let rack = Rack2::new();
+        assert_eq!(rack.min(), Some(&1));
+        assert_eq!(rack.max(), Some(&4));
+        assert_eq!(rack.sum(), 9);
+    }
 
-        let mut unit = rack.must_add(10);
+    #[test]
+    fn fold_values_owned_by_rack_into_product() {
+        let rack = Rack4::new();
+        assert_eq!(rack.fold(1, |product, value| product * value), 1);
 
-        assert_mut_ref_i32_editable(&mut unit)
+        for value in [2, 3, 4] {
+            rack.must_insert(value);
+        }
+        let _unit = rack.must_add(100);
+
+        assert_eq!(rack.fold(1, |product, value| product * value), 24);
     }
 
     #[test]
-    fn accept_up_to_the_limit() {
-        let rack = Rack2::new();
+    fn compact_values_owned_by_rack_to_lowest_slots() {
+        let mut rack = ArrayRack::<_, 8, MostRecentlyFreed>::new();
 
-        let _unit1 = rack.must_add(10);
-        let _unit2 = rack.must_add(20);
+        let mut keys: [Key; 6] = core::array::from_fn(|i| rack.must_insert(i * 10));
+        rack.remove(keys[0]);
+        rack.remove(keys[2]);
+        rack.remove(keys[3]);
+
+        let mut moved = 0;
+        rack.compact(|old, new| {
+            moved += 1;
+            for key in keys.iter_mut() {
+                if *key == old {
+                    *key = new;
+                }
+            }
+        });
+
+        assert_eq!(moved, 3);
+        let occupied: [usize; 3] = {
+            let mut iter = rack.iter_occupied().map(|(index, _)| index);
+            core::array::from_fn(|_| iter.next().unwrap())
+        };
+        assert_eq!(occupied, [0, 1, 2]);
+        assert_eq!(rack.get(keys[1]), Some(&10));
+        assert_eq!(rack.get(keys[4]), Some(&40));
+        assert_eq!(rack.get(keys[5]), Some(&50));
+        assert_bitmap_in_sync(&rack);
     }
 
     #[test]
-    #[should_panic(expected = "The rack is full")]
-    fn rejects_over_the_limit_with_panic_on_must_add() {
-        let rack = Rack2::new();
+    fn keep_values_held_by_units_in_place_when_compacting() {
+        let mut buffer = [const { Slot::new() }; 4];
+        let mut rack = RackSlice::new(&mut buffer);
 
-        let _unit1 = rack.must_add(10);
-        let _unit2 = rack.must_add(20);
-        let _unit3 = rack.must_add(30);
+        let key1 = rack.must_insert(10);
+        rack.must_add(20).leak();
+        let mut key3 = rack.must_insert(30);
+        rack.remove(key1);
+
+        rack.compact(|old, new| {
+            assert_eq!(old, key3);
+            key3 = new;
+        });
+
+        assert_eq!(key3.index(), 0);
+        assert_eq!(rack.get(key3), Some(&30));
+        assert_eq!(rack.must_insert(40).index(), 2);
     }
 
     #[test]
-    fn rejects_over_the_limit_with_error_on_add() {
-        let rack = Rack2::new();
+    fn hash_racks_consistently_with_equality() {
+        use std::collections::hash_map::DefaultHasher;
 
-        let _unit1 = rack.add(10).unwrap();
-        let _unit2 = rack.add(20).unwrap();
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
 
-        // Allow unreachable patterns in case more error types are added to
-        // AddUnitError, so the match would panic on the default arm.
-        #[allow(unreachable_patterns)]
-        match rack
-            .add(30)
-            .expect_err("Add to full stack should return an error")
-        {
-            AddUnitError::FullRack => (),
-            _ => panic!("Adding over limit returned unexpected error"),
-        };
+        let mut a = Rack4::new();
+        a.must_insert(1);
+        let two = a.must_insert(2);
+        let unit = a.must_add(3);
+
+        let b = Rack4::new();
+        b.must_insert(1);
+        b.must_insert(2);
+
+        assert!(a == b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        drop(unit);
+        a.remove(two);
+        assert!(a != b);
+        assert_ne!(hash_of(&a), hash_of(&b));
     }
 
     #[test]
-    fn accept_more_units_once_old_ones_get_out_of_scope() {
-        let rack = Rack2::new();
+    fn compare_racks_slot_by_slot() {
+        let rack1 = Rack4::new();
+        rack1.must_insert(10);
+        rack1.must_insert(20);
 
-        let _unit1 = rack.must_add(10);
-        {
-            let _unit2 = rack.must_add(20);
+        let rack2 = Rack4::new();
+        rack2.must_insert(10);
+        rack2.must_insert(20);
+
+        let rack3 = Rack4::new();
+        rack3.must_insert(20);
+        rack3.must_insert(10);
+
+        let rack4 = Rack4::new();
+        let _unit = rack4.must_add(0);
+        rack4.must_insert(10);
+        rack4.must_insert(20);
+
+        assert!(rack1 == rack2);
+        assert!(rack1 != rack3);
+        assert!(rack1 != rack4);
+    }
+
+    #[test]
+    fn clone_rack_as_empty_one() {
+        let rack = Rack4::new();
+        rack.must_insert(10);
+        let _unit = rack.must_add(20);
+
+        let clone = rack.clone();
+
+        assert_eq!(clone.capacity(), rack.capacity());
+        assert!(clone.iter_occupied().next().is_none());
+    }
+
+    #[test]
+    fn clone_values_owned_by_rack_keeping_keys() {
+        let mut rack = ArrayRack::<_, 100>::new();
+        let keys: [Key; 80] = core::array::from_fn(|i| rack.must_insert(i));
+        rack.remove(keys[1]);
+        let stale = rack.must_insert(1000);
+        rack.remove(stale);
+        let _unit = rack.must_add(2000);
+
+        let mut clone = rack.clone_values();
+
+        assert!(clone == rack);
+        assert_eq!(clone.get(keys[0]), Some(&0));
+        assert_eq!(clone.get(keys[1]), None);
+        assert_eq!(clone.get(stale), None);
+        assert_eq!(clone.remove(keys[79]), Some(79));
+        assert_eq!(rack.get(keys[79]), Some(&79));
+        assert_bitmap_in_sync(&clone);
+    }
+
+    #[test]
+    fn double_every_value_owned_by_rack() {
+        let mut rack = Rack8::new();
+        let keys: [Key; 4] = core::array::from_fn(|i| rack.must_insert(i));
+        rack.remove(keys[2]);
+
+        for value in rack.values_mut() {
+            *value *= 2;
         }
-        let _unit3 = rack.must_add(30);
+
+        let mut values = rack.values();
+        assert_eq!(values.next(), Some(&0));
+        assert_eq!(values.next(), Some(&2));
+        assert_eq!(values.next(), Some(&6));
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn retain_only_even_values() {
+        use std::rc::Rc;
+
+        let values: [Rc<i32>; 6] = core::array::from_fn(|i| Rc::new(i as i32));
+        let mut rack = Rack8::new();
+        for value in values.iter() {
+            rack.must_insert(Rc::clone(value));
+        }
+
+        rack.retain(|value| **value % 2 == 0);
+
+        for value in values.iter() {
+            let expected = if **value % 2 == 0 { 2 } else { 1 };
+            assert_eq!(Rc::strong_count(value), expected);
+        }
+        assert!(rack.values().map(|value| **value).eq([0, 2, 4]));
+        assert_eq!(rack.must_insert(Rc::new(6)).index(), 1);
+        assert_bitmap_in_sync(&rack);
+    }
+
+    #[test]
+    fn never_resolve_key_to_value_owned_by_unit() {
+        let rack = Rack1::new();
+
+        let key = rack.must_insert(10);
+        // Keys issued by another rack may collide with the slot of a unit.
+        let other = Rack1::new();
+        let _unit = other.must_add(20);
+
+        assert_eq!(other.get(key), None);
+        assert_eq!(rack.get(key), Some(&10));
+    }
+
+    #[test]
+    fn share_rack_between_keyed_values_and_units() {
+        let rack = Rack4::new();
+
+        let key = rack.must_insert(10);
+        let unit = rack.must_add(20);
+
+        assert_eq!(unit.index(), 1);
+        assert_eq!(rack.get(key), Some(&10));
+        assert!(rack.insert(30).is_ok());
+        assert!(rack.insert(40).is_ok());
+        assert!(rack.add(50).is_err());
+    }
+
+    #[test]
+    fn construct_and_fill_rack_generically() {
+        fn fill<R: Rack<usize> + Default>() -> usize {
+            let rack = R::default();
+            let mut added = 0;
+            while let Ok(unit) = rack.add(added) {
+                unit.leak();
+                added += 1;
+            }
+            added
+        }
+
+        assert_eq!(fill::<Rack0<usize>>(), 0);
+        assert_eq!(fill::<Rack4<usize>>(), 4);
+        assert_eq!(fill::<AlignedRack<usize, 16, 3>>(), 3);
     }
 
     #[test]
     fn measure_memory_overhead_of_rack() {
-        // Rounds up to 8 bytes and takes another 8 for MaybeUninit keept in
-        // RefCell.
-        // https://doc.rust-lang.org/core/mem/union.MaybeUninit.html#layout
+        // Takes another four bytes for the tag of the slot and rounds up to
+        // the alignment of the value. The rack then takes another 48 for its
+        // bitmap, observer and the pointer back to itself.
+
+        use core::mem;
+
+        fn round_up_to(x: usize, align: usize) -> usize {
+            x.div_ceil(align) * align
+        }
+
+        let item_size = mem::size_of::<u64>();
+        let item_align = mem::align_of::<u64>();
+        let rack_size = mem::size_of::<Rack2<u64>>();
+
+        assert_eq!(
+            rack_size,
+            2 * round_up_to(round_up_to(item_size, 4) + 4, item_align.max(4)) + 48
+        );
+
+        // The tag of a 32-bit value doubles its size.
+        let item_size = mem::size_of::<u32>();
+        let rack_size = mem::size_of::<Rack2<u32>>();
+        assert_eq!(rack_size, 2 * 2 * item_size + 48);
+    }
 
+    #[test]
+    fn expose_memory_overhead_of_slot_as_constant() {
         use core::mem;
 
-        fn round_up_to_8(x: usize) -> usize {
-            x + 7 & !7
+        fn round_up_to(x: usize, align: usize) -> usize {
+            x.div_ceil(align) * align
+        }
+
+        fn documented<T>() -> usize {
+            let size = mem::size_of::<T>();
+            let align = mem::align_of::<T>().max(4);
+            round_up_to(round_up_to(size, 4) + 4, align) - size
         }
 
+        assert_eq!(Rack2::<u64>::OVERHEAD_PER_SLOT, documented::<u64>());
+        assert_eq!(Rack2::<u8>::OVERHEAD_PER_SLOT, documented::<u8>());
+        assert_eq!(Rack2::<[u8; 5]>::OVERHEAD_PER_SLOT, documented::<[u8; 5]>());
+        assert_eq!(Rack2::<()>::OVERHEAD_PER_SLOT, documented::<()>());
+        assert_eq!(RackSlice::<u16>::OVERHEAD_PER_SLOT, documented::<u16>());
+    }
+
+    #[test]
+    fn report_memory_footprint_of_rack() {
+        // Each slot of four bytes takes another four for its tag, the rack
+        // then takes another 48 for its bitmap, observer and the pointer back
+        // to itself.
+        let rack = Rack2::<[u8; 4]>::new();
+
+        assert_eq!(rack.memory_bytes(), 2 * (4 + 4) + 48);
+        assert_eq!(rack.value_bytes(), 2 * 4);
+    }
+
+    #[test]
+    fn report_memory_footprint_of_rack_slice_with_its_storage() {
+        let mut buffer = [const { Slot::<[u8; 4]>::new() }; 2];
+        let rack = RackSlice::new(&mut buffer);
+
+        assert_eq!(rack.capacity(), 2);
+        assert_eq!(
+            rack.memory_bytes(),
+            mem::size_of::<RackSlice<[u8; 4]>>() + 2 * (4 + 4)
+        );
+        assert_eq!(rack.value_bytes(), 2 * 4);
+    }
+
+    #[test]
+    fn report_zero_footprint_of_zero_capacity_rack() {
+        let rack = Rack0::<u64>::new();
+
+        assert_eq!(rack.capacity(), 0);
+        assert_eq!(rack.memory_bytes(), 0);
+        assert_eq!(rack.value_bytes(), 0);
+    }
+
+    #[test]
+    fn measure_memory_overhead_of_large_racks() {
+        use core::mem;
+
         let item_size = mem::size_of::<[u8; 4]>();
-        let rack_size = mem::size_of::<Rack2<[u8; 4]>>();
 
-        assert_eq!(rack_size, 2 * (round_up_to_8(item_size) + 8));
+        let rack_size = mem::size_of::<Rack2048<[u8; 4]>>();
+        assert_eq!(rack_size, 2048 * (item_size + 4) + 48);
+
+        let rack_size = mem::size_of::<Rack4096<[u8; 4]>>();
+        assert_eq!(rack_size, 4096 * (item_size + 4) + 48);
+    }
+
+    #[test]
+    fn keep_tag_per_slot_of_zero_sized_values() {
+        use core::mem;
+
+        assert_eq!(mem::size_of::<Rack1024<()>>(), 1024 * 4 + 48);
+
+        let rack = Rack1024::new();
+        for _ in 0..1024 {
+            rack.must_add(()).leak();
+        }
+        assert!(rack.add(()).is_err());
+    }
+
+    #[test]
+    fn select_smallest_rack_fitting_capacity() {
+        let rack: rack_for!(5) = ArrayRack::new();
+        for i in 0..5 {
+            rack.must_add(i).leak();
+        }
+
+        assert_eq!(rack.capacity(), 8);
+
+        let _rack: Rack128<i32> = <rack_for!(i32, 70)>::new();
+    }
+
+    #[test]
+    fn accept_up_to_the_limit_of_large_rack() {
+        let rack = Rack4096::new();
+
+        for i in 0..4096 {
+            rack.must_add(i).leak();
+        }
+
+        assert!(rack.add(4096).is_err());
+    }
+
+    #[test]
+    fn fill_large_rack_sequentially_and_reuse_freed_slot() {
+        const CAPACITY: usize = 1024;
+
+        let rack = Rack1024::new();
+        let mut units: [Option<Unit<usize>>; CAPACITY] = [const { None }; CAPACITY];
+
+        for (i, unit) in units.iter_mut().enumerate() {
+            let added = rack.must_add(i);
+            assert_eq!(added.index(), i);
+            *unit = Some(added);
+        }
+
+        units[500] = None;
+        let unit = rack.must_add(500);
+
+        assert_eq!(unit.index(), 500);
+        assert!(rack.add(CAPACITY).is_err());
+    }
+
+    #[test]
+    fn fill_large_rack_probing_few_slots_per_value() {
+        use crate::slot::AsSlot;
+        use core::cell::Cell;
+
+        // A slot counting how many times the rack looked at it.
+        struct Probed<'c> {
+            slot: Slot<usize>,
+            probes: &'c Cell<usize>,
+        }
+
+        impl AsSlot for Probed<'_> {
+            type Value = usize;
+
+            fn as_slot(&self) -> &Slot<usize> {
+                self.probes.set(self.probes.get() + 1);
+                &self.slot
+            }
+        }
+
+        const CAPACITY: usize = 1024;
+
+        let probes = Cell::new(0);
+        let slots: [Probed; CAPACITY] = core::array::from_fn(|_| Probed {
+            slot: Slot::new(),
+            probes: &probes,
+        });
+        let state = RackState::new(CAPACITY);
+        let storage = Storage::<_, FirstFit> {
+            slots: &slots,
+            state: &state,
+            policy: PhantomData,
+        };
+
+        for i in 0..CAPACITY {
+            assert_eq!(storage.insert(i).unwrap().index(), i);
+        }
+
+        // Scanning each group of 16 slots from its start takes about 20 probes
+        // per value, rescanning the whole rack from its start over 500.
+        assert!(probes.get() <= 4 * CAPACITY, "{} probes", probes.get());
+        assert!(storage.insert(CAPACITY).is_err());
     }
 
     #[test]
@@ -557,7 +4357,7 @@ mod tests {
     }
 
     #[test]
-    #[allow(unused_variables)]
+    #[allow(unused_variables, dead_code)]
     fn exercise_list_demo_from_readme() {
         enum List<'a> {
             Cons(i32, Unit<'a, List<'a>>),
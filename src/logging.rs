@@ -0,0 +1,28 @@
+//! Logging of units using [defmt](https://defmt.ferrous-systems.com), enabled
+//! by the `defmt` feature.
+
+use defmt::{Format, Formatter};
+
+use crate::Unit;
+
+/// Units are formatted transparently as the values they hold.
+impl<T: Format> Format for Unit<'_, T> {
+    fn format(&self, formatter: Formatter) {
+        self.get_ref().format(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rack, Rack64};
+
+    fn assert_format<T: Format>(_: &T) {}
+
+    #[test]
+    fn format_unit_holding_formattable_value() {
+        let rack = Rack64::new();
+        let unit = rack.must_add(5_u32);
+        assert_format(&unit);
+    }
+}
@@ -0,0 +1,120 @@
+//! Units which never drop their value on their own.
+
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+
+use crate::Unit;
+
+/// A [`Unit`](struct.Unit.html) which does not drop its value when it gets out
+/// of the scope, obtained through
+/// [`Unit::into_manually_managed`](struct.Unit.html#method.into_manually_managed).
+///
+/// Once the `ManagedUnit` is gone, the value stays in its slot and the slot
+/// stays occupied. The value must be dropped explicitly through
+/// [`drop_now`](#method.drop_now), which makes the transfer of ownership
+/// visible in the code.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack1::new();
+///
+/// let five = rack.must_add(5).into_manually_managed();
+/// five.drop_now();
+///
+/// assert!(rack.add(6).is_ok());
+/// ```
+pub struct ManagedUnit<'a, T> {
+    unit: ManuallyDrop<Unit<'a, T>>,
+}
+
+impl<'a, T> ManagedUnit<'a, T> {
+    pub(crate) fn new(unit: Unit<'a, T>) -> Self {
+        Self {
+            unit: ManuallyDrop::new(unit),
+        }
+    }
+
+    /// Drop the stored value right away, freeing its slot on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack1::new();
+    ///
+    /// let five = rack.must_add(5).into_manually_managed();
+    /// assert!(rack.add(6).is_err());
+    ///
+    /// five.drop_now();
+    /// assert!(rack.add(6).is_ok());
+    /// ```
+    pub fn drop_now(self) {
+        drop(ManuallyDrop::into_inner(self.unit));
+    }
+}
+
+impl<T> Deref for ManagedUnit<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.unit.get_ref()
+    }
+}
+
+impl<T> DerefMut for ManagedUnit<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.unit.get_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ManagedUnit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ManagedUnit")
+            .field(self.unit.get_ref())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rack, Rack1};
+
+    use core::cell::Cell;
+
+    struct Counted<'c>(&'c Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn drop_value_only_once_drop_now_is_called() {
+        let drops = Cell::new(0);
+        let rack = Rack1::new();
+
+        let unit = rack.must_add(Counted(&drops)).into_manually_managed();
+        assert_eq!(drops.get(), 0);
+
+        unit.drop_now();
+        assert_eq!(drops.get(), 1);
+        assert!(rack.add(Counted(&drops)).is_ok());
+    }
+
+    #[test]
+    fn keep_value_in_its_slot_when_unit_gets_out_of_scope() {
+        let drops = Cell::new(0);
+        let rack = Rack1::new();
+
+        {
+            let _unit = rack.must_add(Counted(&drops)).into_manually_managed();
+        }
+
+        assert_eq!(drops.get(), 0);
+        assert!(rack.add(Counted(&drops)).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Reporting of claimed and released slots to an observer registered on a
+//! rack for the duration of a call.
+
+use core::cell::Cell;
+use core::ptr::NonNull;
+
+use crate::RackEvent;
+
+// A pointer to the observer with its lifetime erased. It is valid only while
+// `Observer::observe` borrowing the observer runs.
+type Callback = *mut dyn FnMut(RackEvent);
+
+// The observer currently registered on a rack, if any. It is kept as a thin
+// pointer to a reference living on the stack of `observe`, so it takes a
+// single word no matter the type of the observer.
+pub(crate) struct Observer {
+    current: Cell<Option<NonNull<Callback>>>,
+}
+
+// An observer is only registered while the rack is borrowed by `observe`, so
+// the rack cannot be sent to another thread while it points to one.
+unsafe impl Send for Observer {}
+
+impl Observer {
+    pub(crate) const fn new() -> Self {
+        Self {
+            current: Cell::new(None),
+        }
+    }
+
+    // Report events to the given observer while calling `f`. The observer
+    // registered before is put back afterwards, even if `f` panics, so the
+    // rack never points to an observer which is gone.
+    pub(crate) fn observe<R>(
+        &self,
+        mut observer: impl FnMut(RackEvent),
+        f: impl FnOnce() -> R,
+    ) -> R {
+        struct Restore<'a> {
+            observer: &'a Observer,
+            previous: Option<NonNull<Callback>>,
+        }
+
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                self.observer.current.set(self.previous);
+            }
+        }
+
+        let mut callback: &mut dyn FnMut(RackEvent) = &mut observer;
+        // Declared after the callback, so it is dropped before it.
+        let _restore = Restore {
+            observer: self,
+            previous: self.current.get(),
+        };
+        self.current.set(Some(NonNull::from(&mut callback).cast()));
+        f()
+    }
+
+    pub(crate) fn notify(&self, event: RackEvent) {
+        // The observer is taken out while it runs, so events caused by the
+        // observer itself are not reported back to it while it is borrowed.
+        if let Some(callback) = self.current.take() {
+            // This is safe since the pointer is registered only while
+            // `observe` keeps the callback and the observer alive.
+            unsafe { (*callback.as_ptr().read())(event) };
+            self.current.set(Some(callback));
+        }
+    }
+}
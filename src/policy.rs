@@ -0,0 +1,205 @@
+//! Policies choosing which free slot of a rack receives a new value.
+
+use core::cmp;
+
+use crate::bitmap::Bitmap;
+
+/// A policy choosing which free slot of an [`ArrayRack`](struct.ArrayRack.html)
+/// receives a newly added value.
+///
+/// The policy is given as the last type parameter of the rack, defaulting to
+/// [`FirstFit`](struct.FirstFit.html).
+///
+/// # Examples
+///
+/// Fill the rack starting from its last slot:
+///
+/// ```
+/// # use heapnotize::*;
+/// struct LastFit;
+///
+/// impl SlotPolicy for LastFit {
+///     fn select(occupancy: &Occupancy) -> Option<usize> {
+///         (0..occupancy.capacity())
+///             .rev()
+///             .find(|&index| occupancy.is_free(index))
+///     }
+/// }
+///
+/// let rack = ArrayRack::<_, 4, LastFit>::new();
+/// assert_eq!(rack.must_add(1).index(), 3);
+/// ```
+pub trait SlotPolicy {
+    /// Select a free slot for a new value, or return `None` if the rack
+    /// should be treated as full.
+    ///
+    /// # Panics
+    ///
+    /// The rack panics if the returned slot is not free.
+    fn select(occupancy: &Occupancy) -> Option<usize>;
+}
+
+/// Policy storing new values in the free slot with the lowest index.
+///
+/// This keeps the allocation order deterministic, as described in
+/// [allocation order](trait.Rack.html#allocation-order). It is the default
+/// policy of racks.
+pub struct FirstFit;
+
+impl SlotPolicy for FirstFit {
+    fn select(occupancy: &Occupancy) -> Option<usize> {
+        occupancy.first_free()
+    }
+}
+
+/// Policy storing new values in the slot released most recently, if it is
+/// still free.
+///
+/// In tight loops of adding and dropping values, this hands out the slot
+/// which is most likely still in the cache, even if there are free slots with
+/// lower indexes. When the slot released most recently was taken already, the
+/// free slot with the lowest index is used.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = ArrayRack::<_, 4, MostRecentlyFreed>::new();
+///
+/// let a = rack.must_add(1);
+/// let b = rack.must_add(2);
+/// drop(a);
+/// drop(b);
+///
+/// assert_eq!(rack.must_add(3).index(), 1);
+/// ```
+pub struct MostRecentlyFreed;
+
+impl SlotPolicy for MostRecentlyFreed {
+    fn select(occupancy: &Occupancy) -> Option<usize> {
+        occupancy.last_freed().or_else(|| occupancy.first_free())
+    }
+}
+
+/// A view of occupancy of slots of a rack, given to a
+/// [`SlotPolicy`](trait.SlotPolicy.html) to select a free slot from.
+pub struct Occupancy<'a> {
+    pub(crate) bitmap: &'a Bitmap,
+    pub(crate) capacity: usize,
+    pub(crate) is_free: &'a dyn Fn(usize) -> bool,
+}
+
+impl Occupancy<'_> {
+    /// Get the number of slots of the rack.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Check whether the slot of the given index is free. Slots past the
+    /// capacity of the rack are never free.
+    pub fn is_free(&self, index: usize) -> bool {
+        index < self.capacity && (self.is_free)(index)
+    }
+
+    /// Get the index of the free slot with the lowest index, if there is any.
+    ///
+    /// Unlike scanning the slots using [`is_free`](#method.is_free), this
+    /// skips fully occupied parts of the rack quickly.
+    pub fn first_free(&self) -> Option<usize> {
+        let group = self.bitmap.first_free_group(self.capacity)?;
+        // Slots before the cursor are known to be occupied, there is no need
+        // to look at them again.
+        let start = cmp::max(group.start, self.bitmap.cursor());
+        let index = (start..group.end)
+            .find(|&index| (self.is_free)(index))
+            .unwrap_or_else(|| unreachable!("the bitmap is out of sync with the slots"));
+        self.bitmap.skip_to(index);
+        Some(index)
+    }
+
+    /// Get the index of the slot released most recently, if it is still free.
+    pub fn last_freed(&self) -> Option<usize> {
+        self.bitmap
+            .last_freed()
+            .filter(|&index| self.is_free(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddUnitError, ArrayRack, Rack};
+
+    struct LastFit;
+
+    impl SlotPolicy for LastFit {
+        fn select(occupancy: &Occupancy) -> Option<usize> {
+            (0..occupancy.capacity())
+                .rev()
+                .find(|&index| occupancy.is_free(index))
+        }
+    }
+
+    // Add three values, drop the first and the last one of them and add
+    // another two, returning indexes of the latter.
+    fn select_after_drops<P: SlotPolicy>() -> [usize; 2] {
+        let rack = ArrayRack::<_, 4, P>::new();
+
+        let unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+        let unit3 = rack.must_add(30);
+        drop(unit1);
+        drop(unit3);
+
+        let unit4 = rack.must_add(40);
+        let unit5 = rack.must_add(50);
+        [unit4.index(), unit5.index()]
+    }
+
+    #[test]
+    fn select_slots_according_to_policy() {
+        assert_eq!(select_after_drops::<FirstFit>(), [0, 2]);
+        assert_eq!(select_after_drops::<MostRecentlyFreed>(), [2, 0]);
+        assert_eq!(select_after_drops::<LastFit>(), [3, 1]);
+    }
+
+    #[test]
+    fn drop_partially_added_array_once_policy_refuses_free_slot() {
+        // Hands out only the first two slots, leaving the rest free.
+        struct FirstTwo;
+
+        impl SlotPolicy for FirstTwo {
+            fn select(occupancy: &Occupancy) -> Option<usize> {
+                (0..2).find(|&index| occupancy.is_free(index))
+            }
+        }
+
+        let rack = ArrayRack::<_, 4, FirstTwo>::new();
+
+        assert_eq!(
+            rack.add_array([1, 2, 3]).unwrap_err(),
+            AddUnitError::FullRack { capacity: 4 }
+        );
+        assert!(rack.iter_occupied().next().is_none());
+        assert_eq!(rack.stats().used, 0);
+
+        let [unit1, unit2] = rack.add_array([1, 2]).unwrap();
+        assert_eq!((unit1.index(), unit2.index()), (0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "the slot policy selected an occupied slot")]
+    fn reject_occupied_slot_selected_by_policy() {
+        struct Always0;
+
+        impl SlotPolicy for Always0 {
+            fn select(_: &Occupancy) -> Option<usize> {
+                Some(0)
+            }
+        }
+
+        let rack = ArrayRack::<_, 4, Always0>::new();
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+    }
+}
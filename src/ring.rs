@@ -0,0 +1,256 @@
+//! A variant of the rack overwriting its oldest values once it is full.
+
+use core::marker::PhantomData;
+use core::ptr;
+
+use crate::slot::Slot;
+use crate::storage::{RackState, Storage};
+use crate::Key;
+
+/// A rack holding up to `N` values of a type `T`, evicting the oldest value
+/// when a new one is pushed to it while it is full.
+///
+/// This is useful for keeping a rolling window of the last `N` samples. All
+/// values are owned by the rack and accessible through a
+/// [`Key`](struct.Key.html). Once a value gets evicted, it is dropped and its
+/// key stops resolving to any value.
+///
+/// Since values held by a [`Unit`](struct.Unit.html) cannot be evicted,
+/// `RingRack` does not implement the [`Rack`](trait.Rack.html) trait.
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let mut rack = RingRack::<_, 2>::new();
+///
+/// let one = rack.push(1);
+/// rack.push(2);
+/// rack.push(3);
+///
+/// assert_eq!(rack.get(one), None);
+/// assert!(rack.iter().eq(&[2, 3]));
+/// ```
+pub struct RingRack<T, const N: usize> {
+    slots: [Slot<T>; N],
+    state: RackState,
+    // Index of the slot holding the oldest value once the rack is full. Slots
+    // are filled in order and never released except for eviction, so this is
+    // also the slot the next value goes to.
+    cursor: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> RingRack<T, N> {
+    /// Initialize a new ring Rack with a capacity of `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = RingRack::<i32, 64>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { Slot::new() }; N],
+            state: RackState::new(N),
+            cursor: 0,
+            len: 0,
+        }
+    }
+
+    /// Store a value owned by the rack and return a key to access it. If the
+    /// rack is full, its oldest value is dropped to make room for the new one.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the capacity of the rack is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut rack = RingRack::<_, 64>::new();
+    /// let five = rack.push(5);
+    /// assert_eq!(rack.get(five), Some(&5));
+    /// ```
+    pub fn push(&mut self, value: T) -> Key {
+        assert!(N > 0, "The ring rack has no capacity");
+        if self.len == N {
+            let oldest = Key {
+                index: self.cursor,
+                generation: self.slots[self.cursor].generation(),
+            };
+            // This is safe since the rack is borrowed mutably.
+            let evicted = unsafe { self.storage().remove(oldest) };
+            self.len -= 1;
+            // The rack is consistent before the evicted value is dropped, in
+            // case its destructor panics.
+            drop(evicted);
+        }
+        let key = self
+            .storage()
+            .insert(value)
+            .unwrap_or_else(|_| unreachable!("the ring rack has no free slot"));
+        debug_assert_eq!(key.index, self.cursor);
+        self.len += 1;
+        self.cursor = (self.cursor + 1) % N;
+        key
+    }
+
+    /// Get a reference to a value stored under the given key, unless it was
+    /// evicted already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut rack = RingRack::<_, 1>::new();
+    ///
+    /// let five = rack.push(5);
+    /// assert_eq!(rack.get(five), Some(&5));
+    ///
+    /// rack.push(6);
+    /// assert_eq!(rack.get(five), None);
+    /// ```
+    pub fn get(&self, key: Key) -> Option<&T> {
+        self.storage().get(key)
+    }
+
+    /// Get a mutable reference to a value stored under the given key, unless
+    /// it was evicted already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut rack = RingRack::<_, 64>::new();
+    ///
+    /// let number = rack.push(5);
+    /// *rack.get_mut(number).unwrap() = 10;
+    ///
+    /// assert_eq!(rack.get(number), Some(&10));
+    /// ```
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        // This is safe since the rack is borrowed mutably.
+        unsafe { self.storage().get_mut(key) }
+    }
+
+    /// Get the number of values stored on the rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut rack = RingRack::<_, 2>::new();
+    /// for i in 0..3 {
+    ///     rack.push(i);
+    /// }
+    /// assert_eq!(rack.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether there are no values stored on the rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = RingRack::<i32, 64>::new();
+    /// assert!(rack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the stored values, starting from the oldest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut rack = RingRack::<_, 3>::new();
+    /// for i in 0..5 {
+    ///     rack.push(i);
+    /// }
+    /// assert!(rack.iter().eq(&[2, 3, 4]));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let storage = self.storage();
+        // Until the rack is full, the cursor points right past the newest
+        // value and the oldest one is at the start.
+        let oldest = if self.len == N { self.cursor } else { 0 };
+        (0..self.len).filter_map(move |offset| storage.get_at((oldest + offset) % N))
+    }
+
+    fn storage(&self) -> Storage<'_, Slot<T>> {
+        Storage {
+            slots: &self.slots,
+            state: &self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for RingRack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Values can be borrowed from the rack only while it is borrowed too, so
+// unlike other racks, this one can drop them together with itself.
+impl<T, const N: usize> Drop for RingRack<T, N> {
+    fn drop(&mut self) {
+        for index in 0..N {
+            // This is safe since the rack is borrowed mutably.
+            if let Some(value) = unsafe { self.storage().get_at_mut(index) } {
+                unsafe {
+                    ptr::drop_in_place(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    use std::rc::Rc;
+
+    #[test]
+    fn evict_oldest_values_when_ring_rack_is_full() {
+        let first = Rc::new(1);
+        let second = Rc::new(2);
+        let mut rack = RingRack::<_, 3>::new();
+
+        let first_key = rack.push(Rc::clone(&first));
+        let second_key = rack.push(Rc::clone(&second));
+        for i in 3..6 {
+            rack.push(Rc::new(i));
+        }
+
+        assert_eq!(Rc::strong_count(&first), 1);
+        assert_eq!(Rc::strong_count(&second), 1);
+        assert_eq!(rack.get(first_key), None);
+        assert_eq!(rack.get(second_key), None);
+        assert!(rack.iter().map(|value| **value).eq([3, 4, 5]));
+        assert_eq!(rack.len(), 3);
+    }
+
+    #[test]
+    fn drop_values_together_with_ring_rack() {
+        let value = Rc::new(1);
+        let mut rack = RingRack::<_, 2>::new();
+
+        rack.push(Rc::clone(&value));
+        drop(rack);
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,65 @@
+//! Serialization of units using [serde](https://serde.rs), enabled by the
+//! `serde` feature.
+
+use serde::{Serialize, Serializer};
+
+use crate::Unit;
+
+/// Units are serialized transparently as the values they hold.
+///
+/// Deserialization is not provided, since a unit cannot exist without a rack
+/// to be allocated on. It can be implemented using a `DeserializeSeed`
+/// carrying a reference to the rack, which deserializes the value first and
+/// then adds it to the rack:
+///
+/// ```
+/// # use heapnotize::*;
+/// use core::marker::PhantomData;
+/// use serde::de::{DeserializeSeed, Deserialize, Deserializer, Error, IntoDeserializer};
+///
+/// struct UnitSeed<'r, T, R>(&'r R, PhantomData<T>);
+///
+/// impl<'de, 'r, T: Deserialize<'de> + 'r, R: Rack<T>> DeserializeSeed<'de> for UnitSeed<'r, T, R> {
+///     type Value = Unit<'r, T>;
+///
+///     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+///         let value = T::deserialize(deserializer)?;
+///         self.0.add(value).map_err(D::Error::custom)
+///     }
+/// }
+///
+/// let rack = Rack64::<i32>::new();
+/// let deserializer = IntoDeserializer::<serde::de::value::Error>::into_deserializer(5);
+/// let five = UnitSeed(&rack, PhantomData).deserialize(deserializer).unwrap();
+/// assert_eq!(*five, 5);
+/// ```
+impl<T: Serialize> Serialize for Unit<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get_ref().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+
+    use crate::{Rack, Rack64};
+
+    #[test]
+    fn serialize_unit_as_its_value() {
+        let rack = Rack64::new();
+        let five = rack.must_add(5);
+
+        assert_ser_tokens(&five, &[Token::I32(5)]);
+    }
+
+    #[test]
+    fn serialize_nested_units() {
+        let rack = Rack64::new();
+        let inner = rack.must_add(5);
+        let outer = Rack64::new();
+        let outer = outer.must_add(Some(inner));
+
+        assert_ser_tokens(&outer, &[Token::Some, Token::I32(5)]);
+    }
+}
@@ -0,0 +1,433 @@
+//! Units sharing ownership of a single value, counting references to it.
+
+use core::cell::Cell;
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr;
+
+use crate::Unit;
+
+/// A value stored on a rack together with the counts of
+/// [`SharedUnit`](struct.SharedUnit.html)s and
+/// [`WeakUnit`](struct.WeakUnit.html)s referring to it.
+///
+/// Racks need to be initialized to hold `Shared<T>` in order to store shared
+/// values using [`add_shared`](trait.Rack.html#method.add_shared). The counts
+/// are kept in the slot next to the value, taking up two `usize` on top of it.
+pub struct Shared<T> {
+    strong: Cell<usize>,
+    // All the strong owners together hold a single weak reference, so the
+    // slot is released once both the strong and weak owners are gone.
+    weak: Cell<usize>,
+    // The value is dropped once the last strong owner is gone, possibly
+    // before the slot is released.
+    value: ManuallyDrop<T>,
+}
+
+/// A type serving as a shared owner of a value stored on the
+/// [`Rack`](trait.Rack.html).
+///
+/// It works like `Rc` with the value kept on the rack instead of the heap.
+/// Cloning a `SharedUnit` produces another owner of the same value. Once the
+/// last of them gets out of the scope, the value gets dropped and its slot is
+/// released. The value can only be accessed immutably.
+///
+/// Shared units are obtained through
+/// [`add_shared`](trait.Rack.html#method.add_shared).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+///
+/// let five = rack.must_add_shared(5);
+/// let also_five = five.clone();
+///
+/// assert!(SharedUnit::ptr_eq(&five, &also_five));
+/// assert_eq!(SharedUnit::strong_count(&five), 2);
+/// ```
+pub struct SharedUnit<'a, T> {
+    // The unit is shared by all the owners, the last one of them drops it.
+    // Only shared references to the value are ever handed out.
+    unit: ManuallyDrop<Unit<'a, Shared<T>>>,
+}
+
+impl<T> Shared<T> {
+    // Wrap a value owned by a single shared unit.
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            strong: Cell::new(1),
+            weak: Cell::new(1),
+            value: ManuallyDrop::new(value),
+        }
+    }
+}
+
+impl<'a, T> SharedUnit<'a, T> {
+    // Take over the unit holding a freshly created shared value.
+    pub(crate) fn from_unit(unit: Unit<'a, Shared<T>>) -> Self {
+        Self {
+            unit: ManuallyDrop::new(unit),
+        }
+    }
+
+    /// Get a reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let five = rack.must_add_shared(5);
+    /// assert_eq!(*five.get_ref(), 5);
+    /// ```
+    pub fn get_ref(&self) -> &T {
+        &self.shared().value
+    }
+
+    /// Get the index of the `Rack` slot occupied by the shared value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    /// let five = rack.must_add_shared(5);
+    /// assert_eq!(five.index(), 0);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.unit.index()
+    }
+
+    /// Get the number of `SharedUnit`s owning the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add_shared(5);
+    /// let also_five = five.clone();
+    /// assert_eq!(SharedUnit::strong_count(&five), 2);
+    ///
+    /// drop(also_five);
+    /// assert_eq!(SharedUnit::strong_count(&five), 1);
+    /// ```
+    pub fn strong_count(this: &Self) -> usize {
+        this.shared().strong.get()
+    }
+
+    /// Get the number of [`WeakUnit`](struct.WeakUnit.html)s referring to
+    /// the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add_shared(5);
+    /// let _weak_five = SharedUnit::downgrade(&five);
+    ///
+    /// assert_eq!(SharedUnit::weak_count(&five), 1);
+    /// ```
+    pub fn weak_count(this: &Self) -> usize {
+        this.shared().weak.get() - 1
+    }
+
+    /// Create a [`WeakUnit`](struct.WeakUnit.html) referring to the value
+    /// without keeping it alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add_shared(5);
+    /// let weak_five = SharedUnit::downgrade(&five);
+    ///
+    /// assert_eq!(*weak_five.upgrade().unwrap(), 5);
+    /// ```
+    pub fn downgrade(this: &Self) -> WeakUnit<'a, T> {
+        increment(&this.shared().weak);
+        WeakUnit {
+            unit: alias(&this.unit),
+        }
+    }
+
+    /// Check whether two shared units own the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let a = rack.must_add_shared(5);
+    /// let b = rack.must_add_shared(5);
+    ///
+    /// assert!(SharedUnit::ptr_eq(&a, &a.clone()));
+    /// assert!(!SharedUnit::ptr_eq(&a, &b));
+    /// ```
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        ptr::eq(a.unit.as_ptr(), b.unit.as_ptr())
+    }
+
+    fn shared(&self) -> &Shared<T> {
+        self.unit.get_ref()
+    }
+}
+
+impl<T> Clone for SharedUnit<'_, T> {
+    fn clone(&self) -> Self {
+        increment(&self.shared().strong);
+        Self {
+            unit: alias(&self.unit),
+        }
+    }
+}
+
+/// When the last SharedUnit gets out of scope, it will drop the stored value.
+/// Its slot on the Rack is released once there are no WeakUnits left either.
+impl<T> Drop for SharedUnit<'_, T> {
+    fn drop(&mut self) {
+        let strong = &self.shared().strong;
+        strong.set(strong.get() - 1);
+        if strong.get() != 0 {
+            return;
+        }
+        // The weak reference held by the strong owners is dropped after the
+        // value, even if its destructor panics.
+        let _weak = WeakUnit {
+            unit: alias(&self.unit),
+        };
+        // This is safe since this was the last strong owner and weak owners
+        // never access the value once the strong count drops to zero. Only
+        // the value is borrowed mutably, not the counts.
+        unsafe {
+            ManuallyDrop::drop(&mut *ptr::addr_of_mut!((*self.unit.slot.as_ptr()).value));
+        }
+    }
+}
+
+impl<T> Deref for SharedUnit<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get_ref()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedUnit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedUnit")
+            .field("value", self.get_ref())
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+/// A weak reference to a value owned by [`SharedUnit`](struct.SharedUnit.html)s.
+///
+/// It does not keep the value alive, which allows breaking reference cycles.
+/// It keeps the slot of the value occupied though, until the last `WeakUnit`
+/// gets out of the scope.
+///
+/// Weak units are obtained through
+/// [`SharedUnit::downgrade`](struct.SharedUnit.html#method.downgrade).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let rack = Rack64::new();
+///
+/// let five = rack.must_add_shared(5);
+/// let weak_five = SharedUnit::downgrade(&five);
+/// assert!(weak_five.upgrade().is_some());
+///
+/// drop(five);
+/// assert!(weak_five.upgrade().is_none());
+/// ```
+pub struct WeakUnit<'a, T> {
+    unit: ManuallyDrop<Unit<'a, Shared<T>>>,
+}
+
+impl<'a, T> WeakUnit<'a, T> {
+    /// Get a [`SharedUnit`](struct.SharedUnit.html) owning the value, unless
+    /// it was dropped already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add_shared(5);
+    /// let weak_five = SharedUnit::downgrade(&five);
+    ///
+    /// assert_eq!(*weak_five.upgrade().unwrap(), 5);
+    /// ```
+    pub fn upgrade(&self) -> Option<SharedUnit<'a, T>> {
+        let strong = &self.shared().strong;
+        if strong.get() == 0 {
+            return None;
+        }
+        increment(strong);
+        Some(SharedUnit {
+            unit: alias(&self.unit),
+        })
+    }
+
+    /// Get the number of [`SharedUnit`](struct.SharedUnit.html)s owning the
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = Rack64::new();
+    ///
+    /// let five = rack.must_add_shared(5);
+    /// let weak_five = SharedUnit::downgrade(&five);
+    /// assert_eq!(weak_five.strong_count(), 1);
+    ///
+    /// drop(five);
+    /// assert_eq!(weak_five.strong_count(), 0);
+    /// ```
+    pub fn strong_count(&self) -> usize {
+        self.shared().strong.get()
+    }
+
+    // Only the counts may be accessed, the value may have been dropped.
+    fn shared(&self) -> &Shared<T> {
+        self.unit.get_ref()
+    }
+}
+
+impl<T> Clone for WeakUnit<'_, T> {
+    fn clone(&self) -> Self {
+        increment(&self.shared().weak);
+        Self {
+            unit: alias(&self.unit),
+        }
+    }
+}
+
+/// When the last WeakUnit gets out of scope and there are no SharedUnits
+/// left, it will release the slot of the value on the Rack.
+impl<T> Drop for WeakUnit<'_, T> {
+    fn drop(&mut self) {
+        let weak = &self.shared().weak;
+        weak.set(weak.get() - 1);
+        if weak.get() == 0 {
+            // This is safe since this was the last owner of the unit. The
+            // value itself was dropped already and dropping `Shared` does not
+            // touch it.
+            unsafe { ManuallyDrop::drop(&mut self.unit) };
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakUnit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("(WeakUnit)")
+    }
+}
+
+// Create another handle to the unit holding the shared value. Only the last
+// one of the handles may drop it.
+fn alias<'a, T>(unit: &Unit<'a, Shared<T>>) -> ManuallyDrop<Unit<'a, Shared<T>>> {
+    ManuallyDrop::new(Unit {
+        slot: unit.slot,
+        index: unit.index,
+        state: unit.state,
+    })
+}
+
+fn increment(count: &Cell<usize>) {
+    count.set(
+        count
+            .get()
+            .checked_add(1)
+            .expect("The reference count overflowed"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{Rack, Rack1};
+
+    use std::rc::Rc;
+
+    #[test]
+    fn count_owners_of_shared_unit() {
+        let rack = Rack1::new();
+
+        let unit1 = rack.must_add_shared(10);
+        let unit2 = unit1.clone();
+        let unit3 = unit2.clone();
+        assert_eq!(SharedUnit::strong_count(&unit1), 3);
+
+        drop(unit1);
+        drop(unit3);
+        assert_eq!(SharedUnit::strong_count(&unit2), 1);
+        assert_eq!(*unit2, 10);
+    }
+
+    #[test]
+    fn release_slot_once_last_shared_unit_drops() {
+        let value = Rc::new(10);
+        let rack = Rack1::new();
+
+        let unit1 = rack.must_add_shared(Rc::clone(&value));
+        let unit2 = unit1.clone();
+
+        drop(unit1);
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert!(rack.add_shared(Rc::new(20)).is_err());
+
+        drop(unit2);
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert_eq!(rack.must_add_shared(Rc::new(30)).index(), 0);
+    }
+
+    #[test]
+    fn upgrade_weak_unit_while_shared_unit_lives() {
+        let rack = Rack1::new();
+
+        let unit = rack.must_add_shared(10);
+        let weak = SharedUnit::downgrade(&unit);
+
+        let upgraded = weak.upgrade().unwrap();
+        assert!(SharedUnit::ptr_eq(&unit, &upgraded));
+        assert_eq!(SharedUnit::strong_count(&unit), 2);
+    }
+
+    #[test]
+    fn fail_to_upgrade_weak_unit_after_shared_units_drop() {
+        let value = Rc::new(10);
+        let rack = Rack1::new();
+
+        let unit = rack.must_add_shared(Rc::clone(&value));
+        let weak1 = SharedUnit::downgrade(&unit);
+        let weak2 = weak1.clone();
+        drop(unit);
+
+        assert!(weak1.upgrade().is_none());
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        // The slot is kept until the last weak unit drops.
+        drop(weak1);
+        assert!(rack.add_shared(Rc::new(20)).is_err());
+        drop(weak2);
+        assert_eq!(rack.must_add_shared(Rc::new(30)).index(), 0);
+    }
+}
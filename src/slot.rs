@@ -0,0 +1,150 @@
+//! Storage of a single value on a rack.
+
+use core::cell::{Cell, UnsafeCell};
+use core::mem::MaybeUninit;
+
+// Number of the lowest bits of the tag keeping the state of the slot.
+const STATE_BITS: u32 = 2;
+const STATE_MASK: u32 = (1 << STATE_BITS) - 1;
+
+/// A slot of a rack, able to hold a single value of a type `T`.
+///
+/// Racks are composed of slots. They need to be created explicitly only when
+/// providing storage to a [`RackSlice`](struct.RackSlice.html).
+///
+/// # Examples
+///
+/// ```
+/// # use heapnotize::*;
+/// let mut buffer = [const { Slot::<i32>::new() }; 16];
+/// ```
+// The value is placed first, so it starts at the very address of the slot.
+// That allows wrappers of the slot to control the alignment of the value.
+#[repr(C)]
+pub struct Slot<T> {
+    // The value is kept inside `UnsafeCell` to allow us to keep a mutable
+    // reference to it in a `Unit` while keeping the `Rack` immutable. The
+    // carried type is then enclosed in `MaybeUninit`, so we don't need to
+    // require it to implement `Default` to populate the whole rack during its
+    // initialization.
+    pub(crate) value: UnsafeCell<MaybeUninit<T>>,
+    // The lowest bits keep the state of the slot, the rest counts how many
+    // times the slot was released. Only the owner claiming the slot may access
+    // the value, until the slot is released again.
+    tag: Cell<u32>,
+}
+
+// Who owns the value stored in a slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum State {
+    Free = 0,
+    // The value is owned by a `Unit`, only it may access the value.
+    Unit = 1,
+    // The value is owned by the rack and it is accessible through a `Key`.
+    Keyed = 2,
+}
+
+impl<T> Slot<T> {
+    /// Create a new empty slot.
+    pub const fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+            tag: Cell::new(0),
+        }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut T {
+        self.value.get().cast()
+    }
+
+    pub(crate) fn state(&self) -> State {
+        match self.tag.get() & STATE_MASK {
+            0 => State::Free,
+            1 => State::Unit,
+            _ => State::Keyed,
+        }
+    }
+
+    pub(crate) fn is_free(&self) -> bool {
+        self.state() == State::Free
+    }
+
+    pub(crate) fn generation(&self) -> u32 {
+        self.tag.get() >> STATE_BITS
+    }
+
+    // Mark a free slot as owned, keeping its generation.
+    pub(crate) fn claim(&self, state: State) {
+        self.tag.set(self.tag.get() & !STATE_MASK | state as u32);
+    }
+
+    // Mark the slot as free, starting at the given generation.
+    pub(crate) fn reset_to(&self, generation: u32) {
+        self.tag.set(generation << STATE_BITS);
+    }
+
+    // Mark the slot as free and bump its generation. The bits of the
+    // generation overflowing the tag are dropped, so it wraps around.
+    pub(crate) fn release(&self) {
+        self.tag
+            .set(self.generation().wrapping_add(1) << STATE_BITS);
+    }
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Access to the slot of a rack, possibly wrapped to change its layout.
+pub(crate) trait AsSlot {
+    type Value;
+
+    fn as_slot(&self) -> &Slot<Self::Value>;
+}
+
+impl<T> AsSlot for Slot<T> {
+    type Value = T;
+
+    fn as_slot(&self) -> &Slot<T> {
+        self
+    }
+}
+
+// Mark all the given slots as free, forgetting whatever they held before.
+pub(crate) fn reset<T>(slots: &mut [Slot<T>]) {
+    for slot in slots {
+        *slot = Slot::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bump_generation_on_every_release() {
+        let slot = Slot::<i32>::new();
+
+        for _ in 0..3 {
+            slot.claim(State::Keyed);
+            slot.release();
+        }
+
+        assert_eq!(slot.generation(), 3);
+        assert!(slot.is_free());
+    }
+
+    #[test]
+    fn wrap_generation_around() {
+        let slot = Slot::<i32>::new();
+        slot.tag.set(!STATE_MASK);
+        slot.claim(State::Unit);
+
+        slot.release();
+
+        assert_eq!(slot.generation(), 0);
+        assert!(slot.is_free());
+    }
+}
@@ -0,0 +1,1289 @@
+//! Operations shared by all the racks keeping their values in an array of
+//! slots.
+
+use core::cell::Cell;
+use core::cmp;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
+
+use crate::bitmap::Bitmap;
+use crate::observer::Observer;
+use crate::policy::{FirstFit, Occupancy, SlotPolicy};
+use crate::slot::{AsSlot, Slot, State};
+use crate::{AddUnitError, Key, Rack, RackEvent, RackStats, Unit};
+
+// A view of slots of a rack, together with its state and the policy choosing
+// free slots for new values.
+pub(crate) struct Storage<'a, S, P = FirstFit> {
+    pub(crate) slots: &'a [S],
+    pub(crate) state: &'a RackState,
+    pub(crate) policy: PhantomData<P>,
+}
+
+// Everything a rack keeps on top of its slots. The bitmap tracks which slots
+// are occupied, while the observer is told about slots being claimed and
+// released. Units refer to it, so they can release their slot on drop, and
+// reach the rack through its host to store more values there.
+pub(crate) struct RackState {
+    pub(crate) bitmap: Bitmap,
+    pub(crate) observer: Observer,
+    host: Cell<Option<Host>>,
+}
+
+// The rack owning the state with its type erased, together with a function
+// adding a value of the type erased as well to it.
+#[derive(Clone, Copy)]
+struct Host {
+    rack: NonNull<()>,
+    add: AddErased,
+}
+
+type AddErased = unsafe fn(NonNull<()>, NonNull<()>) -> Result<(NonNull<()>, usize), AddUnitError>;
+
+// The rack is refreshed every time units are handed out and it cannot move
+// while they borrow it, so the pointer is only followed while it is valid,
+// no matter the thread.
+unsafe impl Send for Host {}
+
+// Move the value out of the pointer and store it on the rack, dropping it if
+// the rack is full. The caller must pass pointers to a rack of the type `R`
+// and to a value of the type `T` which is not used afterwards.
+unsafe fn add_erased<T, R: Rack<T>>(
+    rack: NonNull<()>,
+    value: NonNull<()>,
+) -> Result<(NonNull<()>, usize), AddUnitError> {
+    let rack = &*rack.cast::<R>().as_ptr();
+    let value = value.cast::<T>().as_ptr().read();
+    let unit = ManuallyDrop::new(rack.add_or_return(value).map_err(|(_, error)| error)?);
+    Ok((NonNull::from(unit.slot).cast(), unit.index))
+}
+
+impl RackState {
+    pub(crate) const fn new(capacity: usize) -> Self {
+        Self {
+            bitmap: Bitmap::new(capacity),
+            observer: Observer::new(),
+            host: Cell::new(None),
+        }
+    }
+
+    // Remember the rack owning the state, so its units can add values to it.
+    // It must be called every time the rack hands out units, since the rack
+    // may have moved since the last time.
+    pub(crate) fn set_host<T, R: Rack<T>>(&self, rack: &R) {
+        self.host.set(Some(Host {
+            rack: NonNull::from(rack).cast(),
+            add: add_erased::<T, R>,
+        }));
+    }
+
+    // Store the value on the rack owning the state, returning the slot it was
+    // stored in and its index. The caller must hold a unit of the type `T`
+    // borrowing the rack, keeping it in place.
+    pub(crate) unsafe fn add_to_host<T>(
+        &self,
+        value: T,
+    ) -> Result<(&Slot<T>, usize), AddUnitError> {
+        let host = self
+            .host
+            .get()
+            .expect("The rack handing out the unit is known");
+        let mut value = ManuallyDrop::new(value);
+        let (slot, index) = (host.add)(host.rack, NonNull::from(&mut *value).cast())?;
+        Ok((&*slot.cast::<Slot<T>>().as_ptr(), index))
+    }
+
+    // Mark the slot of the given index free once its value was dropped or
+    // taken out.
+    pub(crate) fn release(&self, index: usize) {
+        self.bitmap.release(index);
+        self.observer.notify(RackEvent::Freed(index));
+    }
+}
+
+// The view only holds shared references, so it is copyable no matter the
+// type of slots.
+impl<S, P> Clone for Storage<'_, S, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, P> Copy for Storage<'_, S, P> {}
+
+impl<'a, S: AsSlot, P: SlotPolicy> Storage<'a, S, P> {
+    // Store the value in the first free slot and return a unit owning it. The
+    // value is handed back if there is no free slot.
+    pub(crate) fn add(
+        self,
+        value: S::Value,
+    ) -> Result<Unit<'a, S::Value>, (S::Value, AddUnitError)> {
+        match self.claim(State::Unit, value) {
+            Ok((index, slot)) => Ok(Unit {
+                slot,
+                index,
+                state: self.state,
+            }),
+            Err(value) => Err((value, self.full())),
+        }
+    }
+
+    // Store a clone of the value in the first free slot. The value is cloned
+    // only if there is a free slot for it.
+    pub(crate) fn add_cloned(self, value: &S::Value) -> Result<Unit<'a, S::Value>, AddUnitError>
+    where
+        S::Value: Clone,
+    {
+        if self
+            .state
+            .bitmap
+            .first_free_group(self.slots.len())
+            .is_none()
+        {
+            return Err(self.full());
+        }
+        // The slot is claimed only once the clone is done, in case cloning
+        // panics or stores another value on the rack. Should the latter fill
+        // the rack up, the clone is dropped.
+        self.add(value.clone()).map_err(|(_, error)| error)
+    }
+
+    // Store the value in the first free slot, keeping it owned by the rack.
+    pub(crate) fn insert(self, value: S::Value) -> Result<Key, AddUnitError> {
+        let (index, slot) = self.claim(State::Keyed, value).map_err(|_| self.full())?;
+        Ok(Key {
+            index,
+            generation: slot.generation(),
+        })
+    }
+
+    pub(crate) fn get(self, key: Key) -> Option<&'a S::Value> {
+        // This is safe since a slot owned by the rack is never accessed
+        // mutably unless the rack is borrowed mutably.
+        self.keyed(key).map(|slot| unsafe { &*slot.as_ptr() })
+    }
+
+    // The caller must hold a mutable reference to the rack, proving that there
+    // are no other references to the values owned by it.
+    pub(crate) unsafe fn get_mut(self, key: Key) -> Option<&'a mut S::Value> {
+        self.keyed(key).map(|slot| &mut *slot.as_ptr())
+    }
+
+    // The caller must hold a mutable reference to the rack, proving that there
+    // are no other references to the values owned by it.
+    pub(crate) unsafe fn remove(self, key: Key) -> Option<S::Value> {
+        let slot = self.keyed(key)?;
+        let value = slot.as_ptr().read();
+        slot.release();
+        self.state.release(key.index);
+        Some(value)
+    }
+
+    pub(crate) fn get_at(self, index: usize) -> Option<&'a S::Value> {
+        // This is safe for the same reason as in `get`.
+        self.owned(index).map(|slot| unsafe { &*slot.as_ptr() })
+    }
+
+    // The caller must hold a mutable reference to the rack, proving that there
+    // are no other references to the values owned by it.
+    pub(crate) unsafe fn get_at_mut(self, index: usize) -> Option<&'a mut S::Value> {
+        self.owned(index).map(|slot| &mut *slot.as_ptr())
+    }
+
+    // Move values owned by the rack to the free slots with the lowest
+    // indexes, reporting keys of the moved values. Values held by units stay
+    // in place. The caller must hold a mutable reference to the rack, proving
+    // that there are no other references to the values owned by it.
+    pub(crate) unsafe fn compact<F: FnMut(Key, Key)>(self, mut relocated: F) {
+        // Free slots must be picked in order no matter the policy of the
+        // rack, otherwise values could be moved back and forth.
+        let first_fit = Storage::<S, FirstFit> {
+            slots: self.slots,
+            state: self.state,
+            policy: PhantomData,
+        };
+        for index in 0..self.slots.len() {
+            let slot = self.slots[index].as_slot();
+            if slot.state() != State::Keyed {
+                continue;
+            }
+            match first_fit.select() {
+                Some(free) if free < index => {}
+                _ => continue,
+            }
+            let old = Key {
+                index,
+                generation: slot.generation(),
+            };
+            let value = first_fit
+                .remove(old)
+                .unwrap_or_else(|| unreachable!("the slot is owned by the rack"));
+            let new = first_fit
+                .insert(value)
+                .unwrap_or_else(|_| unreachable!("the rack has a free slot"));
+            relocated(old, new);
+        }
+    }
+
+    // The caller must hold a mutable reference to the rack, proving that there
+    // are no other references to the values owned by it.
+    pub(crate) unsafe fn values_mut(self) -> impl Iterator<Item = &'a mut S::Value> {
+        // Each of the slots is visited only once, so no two of the returned
+        // references alias.
+        (0..self.slots.len()).filter_map(move |index| self.get_at_mut(index))
+    }
+
+    // Drop values owned by the rack which fail the predicate, releasing their
+    // slots. The caller must hold a mutable reference to the rack, proving
+    // that there are no other references to the values owned by it.
+    pub(crate) unsafe fn retain<F: FnMut(&S::Value) -> bool>(self, mut f: F) {
+        for index in 0..self.slots.len() {
+            let slot = match self.owned(index) {
+                Some(slot) => slot,
+                None => continue,
+            };
+            if !f(&*slot.as_ptr()) {
+                // The value is taken out of the rack before it is dropped, in
+                // case its destructor panics.
+                drop(self.remove(Key {
+                    index,
+                    generation: slot.generation(),
+                }));
+            }
+        }
+    }
+
+    // Move values owned by the rack to free slots of the target, keeping them
+    // owned by the target. Once the target fills up, the remaining values stay
+    // in place. The caller must hold a mutable reference to the rack, proving
+    // that there are no other references to the values owned by it.
+    pub(crate) unsafe fn drain_into<Q: SlotPolicy>(
+        self,
+        target: Storage<'_, S, Q>,
+    ) -> Result<(), AddUnitError> {
+        for index in 0..self.slots.len() {
+            let slot = match self.owned(index) {
+                Some(slot) => slot,
+                None => continue,
+            };
+            // The value is taken out of the rack only once there is room for
+            // it, so it is never lost.
+            if target.select().is_none() {
+                return Err(target.full());
+            }
+            let value = self
+                .remove(Key {
+                    index,
+                    generation: slot.generation(),
+                })
+                .unwrap_or_else(|| unreachable!("the slot is owned by the rack"));
+            target
+                .insert(value)
+                .unwrap_or_else(|_| unreachable!("the rack has a free slot"));
+        }
+        Ok(())
+    }
+
+    // Store clones of values owned by the rack in the same slots of an empty
+    // rack of the same capacity, keeping generations of all the slots, so keys
+    // resolve to the same values on both. Slots held by units stay free.
+    pub(crate) fn clone_into<Q>(self, target: Storage<'_, S, Q>)
+    where
+        S::Value: Clone,
+    {
+        let len = self.slots.len();
+        for (index, source) in self.slots.iter().enumerate() {
+            let source = source.as_slot();
+            let slot = target.slots[index].as_slot();
+            slot.reset_to(source.generation());
+            if source.state() == State::Keyed {
+                // This is safe for the same reason as in `get`, and since the
+                // target slot is free and not accessible by anybody else.
+                unsafe { slot.as_ptr().write((*source.as_ptr()).clone()) };
+                slot.claim(State::Keyed);
+            }
+            let group = target.state.bitmap.group_of(index, len);
+            if index + 1 == group.end
+                && target.slots[group]
+                    .iter()
+                    .all(|slot| !slot.as_slot().is_free())
+            {
+                target.state.bitmap.set_full(index);
+            }
+        }
+    }
+
+    // Iterate over values owned by the rack.
+    pub(crate) fn iter(self) -> Iter<'a, S::Value> {
+        Iter::new(self.slots)
+    }
+
+    pub(crate) fn iter_occupied(self) -> impl Iterator<Item = (usize, &'a S::Value)> {
+        (0..self.slots.len())
+            .filter_map(move |index| self.get_at(index).map(|value| (index, value)))
+    }
+
+    // Hash values owned by the rack together with indexes of their slots,
+    // followed by their count, so no two different racks hash as a prefix of
+    // one another.
+    pub(crate) fn hash<H: Hasher>(self, state: &mut H)
+    where
+        S::Value: Hash,
+    {
+        let mut count = 0;
+        for (index, value) in self.iter_occupied() {
+            index.hash(state);
+            value.hash(state);
+            count += 1;
+        }
+        state.write_usize(count);
+    }
+
+    pub(crate) fn contains(self, value: &S::Value) -> bool
+    where
+        S::Value: PartialEq,
+    {
+        self.iter_occupied().any(|(_, stored)| stored == value)
+    }
+
+    // Find the first value owned by the rack satisfying the predicate, together
+    // with the index of its slot.
+    pub(crate) fn find<F: FnMut(&S::Value) -> bool>(
+        self,
+        mut f: F,
+    ) -> Option<(usize, &'a S::Value)> {
+        self.iter_occupied().find(|(_, value)| f(value))
+    }
+
+    // Rebuild a unit from a pointer to its value. The caller must guarantee
+    // that the pointer was obtained from `Unit::into_raw` of a unit of this
+    // storage and that no other unit was rebuilt from it since.
+    pub(crate) unsafe fn unit_from_raw(self, ptr: *mut S::Value) -> Unit<'a, S::Value> {
+        let offset = (ptr as usize).wrapping_sub(self.slots.as_ptr() as usize);
+        let index = offset / mem::size_of::<S>();
+        let slot = self
+            .slots
+            .get(index)
+            .map(AsSlot::as_slot)
+            .filter(|slot| slot.as_ptr() == ptr && slot.state() == State::Unit)
+            .expect("The pointer does not refer to a unit of the rack");
+        Unit {
+            slot,
+            index,
+            state: self.state,
+        }
+    }
+
+    // Get the index of the slot the next value would be stored in.
+    pub(crate) fn next_free(self) -> Option<usize> {
+        self.select()
+    }
+
+    // Check whether the slot at the given index holds a value, be it held by a
+    // unit or owned by the rack.
+    pub(crate) fn is_occupied(self, index: usize) -> bool {
+        self.slots
+            .get(index)
+            .is_some_and(|slot| !slot.as_slot().is_free())
+    }
+
+    // Get the distance in bytes between values of two adjacent slots.
+    pub(crate) fn stride(self) -> usize {
+        mem::size_of::<S>()
+    }
+
+    // Count the slots which hold no value.
+    pub(crate) fn free_slots(self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| slot.as_slot().is_free())
+            .count()
+    }
+
+    // Summarize the occupancy of the slots, visiting each of them once.
+    pub(crate) fn stats(self) -> RackStats {
+        let capacity = self.slots.len();
+        let free = self.free_slots();
+        let used = capacity - free;
+        RackStats {
+            capacity,
+            used,
+            free,
+            bytes_used: used * self.stride(),
+            bytes_capacity: capacity * self.stride(),
+        }
+    }
+
+    // Check whether the unit occupies one of the slots of this storage.
+    pub(crate) fn owns(self, unit: &Unit<'_, S::Value>) -> bool {
+        self.slots
+            .get(unit.index)
+            .is_some_and(|slot| ptr::eq(slot.as_slot(), unit.slot))
+    }
+
+    // Find a slot owned by the rack which was not released since the key was
+    // issued.
+    fn keyed(&self, key: Key) -> Option<&'a Slot<S::Value>> {
+        self.owned(key.index)
+            .filter(|slot| slot.generation() == key.generation)
+    }
+
+    // Find a slot at the given index holding a value owned by the rack. Slots
+    // held by units are never exposed, since the unit may be holding a mutable
+    // reference to the value.
+    fn owned(&self, index: usize) -> Option<&'a Slot<S::Value>> {
+        let slot = self.slots.get(index)?.as_slot();
+        if slot.state() == State::Keyed {
+            Some(slot)
+        } else {
+            None
+        }
+    }
+
+    // Claim a free slot chosen by the policy for the given owner and populate
+    // it with the value. The value is handed back if there is no free slot.
+    fn claim(
+        &self,
+        state: State,
+        value: S::Value,
+    ) -> Result<(usize, &'a Slot<S::Value>), S::Value> {
+        let index = match self.select() {
+            Some(index) => index,
+            None => return Err(value),
+        };
+        let slot = self.slots[index].as_slot();
+        slot.claim(state);
+        if index == self.state.bitmap.cursor() {
+            self.state.bitmap.skip_to(index + 1);
+        }
+        // Slots before the cursor are occupied, so only the rest of the group
+        // needs to be checked.
+        let group = self.state.bitmap.group_of(index, self.slots.len());
+        let start = cmp::max(group.start, self.state.bitmap.cursor());
+        if start >= group.end
+            || self.slots[start..group.end]
+                .iter()
+                .all(|slot| !slot.as_slot().is_free())
+        {
+            self.state.bitmap.set_full(index);
+        }
+        // This is safe since the slot was free and now it is claimed by the new
+        // owner only.
+        unsafe {
+            slot.as_ptr().write(value);
+        }
+        self.state.observer.notify(RackEvent::Allocated(index));
+        Ok((index, slot))
+    }
+
+    // The error reported once there is no free slot left.
+    fn full(&self) -> AddUnitError {
+        AddUnitError::FullRack {
+            capacity: self.slots.len(),
+        }
+    }
+
+    // Find a free slot according to the policy.
+    fn select(&self) -> Option<usize> {
+        let is_free = |index: usize| self.slots[index].as_slot().is_free();
+        let index = P::select(&Occupancy {
+            bitmap: &self.state.bitmap,
+            capacity: self.slots.len(),
+            is_free: &is_free,
+        })?;
+        assert!(
+            index < self.slots.len() && is_free(index),
+            "the slot policy selected an occupied slot"
+        );
+        Some(index)
+    }
+}
+
+/// An iterator over values owned by a rack.
+///
+/// It is obtained through [`ArrayRack::iter`](struct.ArrayRack.html#method.iter)
+/// or by iterating over a reference to the rack. Values held by units are
+/// skipped.
+pub struct Iter<'a, T> {
+    // The next slot to visit. Slots may be wrapped in a larger type, so they
+    // are `stride` bytes apart.
+    next: *const Slot<T>,
+    stride: usize,
+    remaining: usize,
+    slots: PhantomData<&'a Slot<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new<S: AsSlot<Value = T>>(slots: &'a [S]) -> Self {
+        // The pointer is derived from the whole slice, so it may be moved
+        // over all of its slots.
+        let offset = slots.first().map_or(0, |first| {
+            first.as_slot() as *const Slot<T> as usize - first as *const S as usize
+        });
+        Self {
+            next: slots.as_ptr().cast::<u8>().wrapping_add(offset).cast(),
+            stride: mem::size_of::<S>(),
+            remaining: slots.len(),
+            slots: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            // This is safe since the pointer stays within the borrowed slice
+            // until all of its slots are visited.
+            let slot = unsafe { &*self.next };
+            self.next = self.next.cast::<u8>().wrapping_add(self.stride).cast();
+            self.remaining -= 1;
+            if slot.state() == State::Keyed {
+                // This is safe for the same reason as in `Storage::get`.
+                return Some(unsafe { &*slot.as_ptr() });
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// Define methods shared by all the racks keeping their values in an array of
+// slots. The rack must provide a `storage` method giving a view of its slots
+// and implement the `Rack` trait. Examples in the documentation start by
+// binding an empty rack with a capacity of 4 to `rack`, using the given code.
+macro_rules! storage_methods {
+    (rack: $rack:literal, rack_mut: $rack_mut:literal $(,)?) => {
+        /// Store a value owned by the rack and return a key to access it.
+        ///
+        /// The value stays on the rack until it is removed using
+        /// [`remove`](#method.remove).
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error in case the `Rack` is fully populated.
+        ///
+        /// # Examples
+        ///
+        /// Keep keys of nodes of a graph in an adjacency list:
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        ///
+        /// let a = rack.insert("a").unwrap();
+        /// let b = rack.insert("b").unwrap();
+        /// let edges = [(a, b), (b, a)];
+        ///
+        /// assert_eq!(rack.get(edges[0].1), Some(&"b"));
+        /// ```
+        pub fn insert(&self, value: T) -> Result<$crate::Key, $crate::AddUnitError> {
+            self.storage().insert(value)
+        }
+
+        /// Store a value owned by the rack and return a key to access it, panic if
+        /// the rack is full.
+        ///
+        /// # Panics
+        ///
+        /// This method will panic in case the `Rack` is fully populated.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let key = rack.must_insert(5);
+        /// ```
+        pub fn must_insert(&self, value: T) -> $crate::Key {
+            self.insert(value).expect("The rack is full")
+        }
+
+        /// Get a reference to a value stored under the given key.
+        ///
+        /// Returns `None` if the value was already removed.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let key = rack.must_insert(5);
+        ///
+        /// assert_eq!(rack.get(key), Some(&5));
+        /// ```
+        pub fn get(&self, key: $crate::Key) -> Option<&T> {
+            self.storage().get(key)
+        }
+
+        /// Get a mutable reference to a value stored under the given key.
+        ///
+        /// Returns `None` if the value was already removed.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        /// let key = rack.must_insert(5);
+        ///
+        /// *rack.get_mut(key).unwrap() = 10;
+        ///
+        /// assert_eq!(rack.get(key), Some(&10));
+        /// ```
+        pub fn get_mut(&mut self, key: $crate::Key) -> Option<&mut T> {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().get_mut(key) }
+        }
+
+        /// Remove a value stored under the given key from the rack and return it.
+        ///
+        /// The slot is freed and all the copies of the key stop resolving. Since
+        /// references obtained through [`get`](#method.get) could point to the
+        /// removed value, this requires a mutable reference to the rack.
+        ///
+        /// Returns `None` if the value was already removed.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        /// let key = rack.must_insert(5);
+        ///
+        /// assert_eq!(rack.remove(key), Some(5));
+        /// assert_eq!(rack.remove(key), None);
+        /// ```
+        pub fn remove(&mut self, key: $crate::Key) -> Option<T> {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().remove(key) }
+        }
+
+        /// Get a reference to a value owned by the rack in the slot of the given
+        /// index.
+        ///
+        /// Only values stored through [`insert`](#method.insert) are visible this
+        /// way. Values added through [`add`](trait.Rack.html#method.add) are held
+        /// by a [`Unit`](struct.Unit.html) and `None` is returned for their slots,
+        /// even though the slots are occupied, since the unit may be changing the
+        /// value through a mutable reference at the same time. Use
+        /// [`is_index_occupied`](#method.is_index_occupied) to check whether a
+        /// slot is occupied at all. `None` is also returned if the slot is free
+        /// or out of range.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        ///
+        /// let key = rack.must_insert(5);
+        /// let unit = rack.must_add(6);
+        ///
+        /// assert_eq!(rack.get_at(key.index()), Some(&5));
+        /// assert_eq!(rack.get_at(unit.index()), None);
+        /// ```
+        pub fn get_at(&self, index: usize) -> Option<&T> {
+            self.storage().get_at(index)
+        }
+
+        /// Get a mutable reference to a value owned by the rack in the slot of the
+        /// given index.
+        ///
+        /// The same rules as for [`get_at`](#method.get_at) apply, `None` is
+        /// returned for slots held by units.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        ///
+        /// rack.must_insert(5);
+        /// *rack.get_at_mut(0).unwrap() = 10;
+        ///
+        /// assert_eq!(rack.get_at(0), Some(&10));
+        /// ```
+        pub fn get_at_mut(&mut self, index: usize) -> Option<&mut T> {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().get_at_mut(index) }
+        }
+
+        /// Iterate over values owned by the rack, together with indexes of their
+        /// slots.
+        ///
+        /// This exposes the physical layout of the rack, e.g. to diagnose its
+        /// fragmentation. Only values stored through [`insert`](#method.insert)
+        /// are yielded. Slots of values added through
+        /// [`add`](trait.Rack.html#method.add) are held by units and they are
+        /// skipped, even though they are occupied, the same as in
+        /// [`get_at`](#method.get_at).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        ///
+        /// rack.must_insert("a");
+        /// let b = rack.must_insert("b");
+        /// rack.must_insert("c");
+        /// rack.remove(b);
+        /// let d = rack.must_add("d");
+        ///
+        /// let mut iter = rack.iter_occupied();
+        /// assert_eq!(iter.next(), Some((0, &"a")));
+        /// assert_eq!(iter.next(), Some((2, &"c")));
+        /// assert_eq!(iter.next(), None);
+        /// ```
+        pub fn iter_occupied(&self) -> impl Iterator<Item = (usize, &T)> {
+            self.storage().iter_occupied()
+        }
+
+        /// Move values owned by the rack to the free slots with the lowest
+        /// indexes, so they occupy a contiguous range of slots where possible.
+        ///
+        /// Keys refer to slots directly, so keys of the moved values stop
+        /// resolving to them. For each of the moved values, the `relocated`
+        /// closure receives its old and new key, so the caller can update keys it
+        /// keeps. Values held by units are never moved and the slots they occupy
+        /// stay in place.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        ///
+        /// let a = rack.must_insert("a");
+        /// let mut b = rack.must_insert("b");
+        /// rack.remove(a);
+        ///
+        /// rack.compact(|old, new| {
+        ///     if old == b {
+        ///         b = new;
+        ///     }
+        /// });
+        ///
+        /// assert_eq!(b.index(), 0);
+        /// assert_eq!(rack.get(b), Some(&"b"));
+        /// ```
+        pub fn compact<F: FnMut($crate::Key, $crate::Key)>(&mut self, relocated: F) {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().compact(relocated) }
+        }
+
+        /// Iterate over values owned by the rack.
+        ///
+        /// The same rules as for [`get_at`](#method.get_at) apply, values held by
+        /// units are skipped, since a unit may be holding a mutable reference to
+        /// its value at the same time. Iterating over a reference to the rack
+        /// does the same.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        ///
+        /// rack.must_insert(1);
+        /// rack.must_insert(2);
+        /// let _unit = rack.must_add(3);
+        ///
+        /// let mut sum = 0;
+        /// for value in &rack {
+        ///     sum += value;
+        /// }
+        /// assert_eq!(sum, 3);
+        /// ```
+        pub fn iter(&self) -> $crate::Iter<'_, T> {
+            self.storage().iter()
+        }
+
+        /// Iterate over values owned by the rack.
+        ///
+        /// This is the same as [`iter`](#method.iter).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(1);
+        /// rack.must_insert(2);
+        ///
+        /// assert_eq!(rack.values().sum::<i32>(), 3);
+        /// ```
+        pub fn values(&self) -> $crate::Iter<'_, T> {
+            self.iter()
+        }
+
+        /// Get the smallest value owned by the rack, or `None` if there is none.
+        ///
+        /// Like the other iterators, this does not consider values held by
+        /// units.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// assert_eq!(rack.min(), None);
+        ///
+        /// rack.must_insert(3);
+        /// rack.must_insert(1);
+        /// assert_eq!(rack.min(), Some(&1));
+        /// ```
+        pub fn min(&self) -> Option<&T>
+        where
+            T: Ord,
+        {
+            self.values().min()
+        }
+
+        /// Get the largest value owned by the rack, or `None` if there is none.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(3);
+        /// rack.must_insert(1);
+        ///
+        /// assert_eq!(rack.max(), Some(&3));
+        /// ```
+        pub fn max(&self) -> Option<&T>
+        where
+            T: Ord,
+        {
+            self.values().max()
+        }
+
+        /// Sum all the values owned by the rack. The sum of an empty rack is the
+        /// sum of an empty iterator, e.g. 0 for numbers.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// assert_eq!(rack.sum(), 0);
+        ///
+        /// rack.must_insert(3);
+        /// rack.must_insert(1);
+        /// assert_eq!(rack.sum(), 4);
+        /// ```
+        pub fn sum(&self) -> T
+        where
+            T: core::iter::Sum + Copy,
+        {
+            self.values().copied().sum()
+        }
+
+        /// Reduce all the values owned by the rack into a single one, starting
+        /// from `init` and combining it with every value in order of their slots.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert("hello");
+        /// rack.must_insert("world");
+        ///
+        /// assert_eq!(rack.fold(0, |length, value| length + value.len()), 10);
+        /// ```
+        pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+            self.values().fold(init, f)
+        }
+
+        /// Iterate over mutable references to values owned by the rack.
+        ///
+        /// Values held by units are skipped, the same as in
+        /// [`iter`](#method.iter).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        /// let five = rack.must_insert(5);
+        ///
+        /// for value in rack.values_mut() {
+        ///     *value += 1;
+        /// }
+        ///
+        /// assert_eq!(rack.get(five), Some(&6));
+        /// ```
+        pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().values_mut() }
+        }
+
+        /// Keep only the values owned by the rack which satisfy the predicate,
+        /// dropping the rest of them and releasing their slots.
+        ///
+        /// Values held by units are never dropped, the predicate is not called
+        /// for them.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        /// for i in 0..4 {
+        ///     rack.must_insert(i);
+        /// }
+        ///
+        /// rack.retain(|value| value % 2 == 0);
+        ///
+        /// assert!(rack.values().eq(&[0, 2]));
+        /// ```
+        pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+            // This is safe since the rack is borrowed mutably.
+            unsafe { self.storage().retain(f) }
+        }
+
+        /// Drop all the values owned by the rack and release their slots.
+        ///
+        /// The rack does not drop values it owns when it is dropped itself,
+        /// see [keys](struct.ArrayRack.html#keys). Values held by leaked units
+        /// stay in place.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        /// rack.must_insert(1);
+        /// rack.must_insert(2);
+        /// rack.must_add(3).leak();
+        ///
+        /// rack.clear();
+        ///
+        /// assert!(rack.values().next().is_none());
+        /// assert_eq!(rack.stats().used, 1);
+        /// ```
+        pub fn clear(&mut self) {
+            self.retain(|_| false);
+        }
+
+        /// Check whether a value equal to the given one is owned by the rack.
+        ///
+        /// Only values stored through [`insert`](#method.insert) are compared.
+        /// Values added through [`add`](trait.Rack.html#method.add) are held by
+        /// units and they are never compared, so `false` is returned for them,
+        /// since a unit may be holding a mutable reference to its value at the
+        /// same time.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack_mut]
+        ///
+        /// let five = rack.must_insert(5);
+        /// assert!(rack.contains(&5));
+        ///
+        /// rack.remove(five);
+        /// assert!(!rack.contains(&5));
+        ///
+        /// let six = rack.must_add(6);
+        /// assert!(!rack.contains(&6));
+        /// ```
+        pub fn contains(&self, value: &T) -> bool
+        where
+            T: PartialEq,
+        {
+            self.storage().contains(value)
+        }
+
+        /// Get a reference to the first value owned by the rack which satisfies
+        /// the predicate.
+        ///
+        /// Values are visited in the order of their slots. Only values stored
+        /// through [`insert`](#method.insert) are passed to the predicate. Values
+        /// added through [`add`](trait.Rack.html#method.add) are held by units
+        /// and they are skipped, same as with [`contains`](#method.contains),
+        /// since a unit may be holding a mutable reference to its value at the
+        /// same time.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(1);
+        /// rack.must_insert(4);
+        ///
+        /// assert_eq!(rack.find(|value| value % 2 == 0), Some(&4));
+        /// assert_eq!(rack.find(|value| *value > 4), None);
+        /// ```
+        pub fn find<F: FnMut(&T) -> bool>(&self, f: F) -> Option<&T> {
+            self.storage().find(f).map(|(_, value)| value)
+        }
+
+        /// Get the index of the slot of the first value owned by the rack which
+        /// satisfies the predicate.
+        ///
+        /// The index can be then passed to [`get_at`](#method.get_at). Values
+        /// added through [`add`](trait.Rack.html#method.add) are held by units
+        /// and they are skipped, the same way as in [`find`](#method.find).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(1);
+        /// let four = rack.must_insert(4);
+        ///
+        /// let index = rack.position(|value| *value == 4);
+        /// assert_eq!(index, Some(four.index()));
+        /// assert_eq!(rack.get_at(index.unwrap()), Some(&4));
+        /// ```
+        pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+            self.storage().find(f).map(|(index, _)| index)
+        }
+
+        /// Check whether the unit holds a value stored on this rack.
+        ///
+        /// This is useful in assertions catching units of different racks being
+        /// mixed up.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let other = Rack4::new();
+        ///
+        /// let five = rack.must_add(5);
+        /// let six = other.must_add(6);
+        ///
+        /// assert!(rack.owns(&five));
+        /// assert!(!rack.owns(&six));
+        /// ```
+        pub fn owns(&self, unit: &$crate::Unit<'_, T>) -> bool {
+            self.storage().owns(unit)
+        }
+
+        /// Get the index of the slot the next added value will be stored in, or
+        /// `None` if the rack is full.
+        ///
+        /// This agrees with the [slot policy](trait.SlotPolicy.html) of the
+        /// rack, which picks the free slot with the lowest index by default.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// assert_eq!(rack.first_free(), Some(0));
+        ///
+        /// let five = rack.must_add(5);
+        /// assert_eq!(rack.first_free(), Some(1));
+        ///
+        /// let rest = rack.add_array([6, 7, 8]).unwrap();
+        /// assert_eq!(rack.first_free(), None);
+        /// ```
+        pub fn first_free(&self) -> Option<usize> {
+            self.storage().next_free()
+        }
+
+        /// Check whether the slot at the given index holds a value, either held by
+        /// a unit or owned by the rack. Indexes out of the capacity of the rack
+        /// are never occupied.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        ///
+        /// let five = rack.must_add(5);
+        /// assert!(rack.is_index_occupied(five.index()));
+        /// assert!(!rack.is_index_occupied(1));
+        /// assert!(!rack.is_index_occupied(4));
+        ///
+        /// drop(five);
+        /// assert!(!rack.is_index_occupied(0));
+        /// ```
+        pub fn is_index_occupied(&self, index: usize) -> bool {
+            self.storage().is_occupied(index)
+        }
+
+        /// Get the distance in bytes between values of two adjacent slots.
+        ///
+        /// Besides the size of the value, this includes the tag of the slot and
+        /// padding, as described in
+        /// [memory requirements](trait.Rack.html#memory-requirements). It is
+        /// useful for laying out DMA descriptors pointing into the rack.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(5_u64);
+        ///
+        /// assert_eq!(rack.value_stride(), 16);
+        /// ```
+        pub fn value_stride(&self) -> usize {
+            self.storage().stride()
+        }
+
+        /// Get the number of bytes taken by all the slots of the rack.
+        ///
+        /// This is the capacity multiplied by the
+        /// [`value_stride`](#method.value_stride), leaving out only the state kept
+        /// by the rack on top of them.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// rack.must_insert(5_u64);
+        ///
+        /// assert_eq!(rack.capacity_bytes(), 4 * 16);
+        /// ```
+        pub fn capacity_bytes(&self) -> usize {
+            $crate::Rack::capacity(self) * self.value_stride()
+        }
+
+        /// Get the number of bytes taken by the free slots of the rack.
+        ///
+        /// This is the number of free slots multiplied by the
+        /// [`value_stride`](#method.value_stride), which makes it easy to express
+        /// the occupancy of the rack in bytes.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let five = rack.must_add(5_u64);
+        ///
+        /// assert_eq!(rack.available_bytes(), 3 * 16);
+        /// ```
+        pub fn available_bytes(&self) -> usize {
+            self.storage().free_slots() * self.value_stride()
+        }
+
+        /// Summarize the occupancy of the rack.
+        ///
+        /// All the numbers are collected in a single pass over the slots, which
+        /// is cheaper than calling [`capacity_bytes`](#method.capacity_bytes),
+        /// [`available_bytes`](#method.available_bytes) and friends one by one.
+        /// Slots held by units and slots of values owned by the rack are both
+        /// counted as used.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let five = rack.must_add(5_u64);
+        ///
+        /// let stats = rack.stats();
+        /// assert_eq!(stats.capacity, 4);
+        /// assert_eq!(stats.used, 1);
+        /// assert_eq!(stats.free, 3);
+        /// assert_eq!(stats.bytes_used, 16);
+        /// assert_eq!(stats.bytes_capacity, 64);
+        /// ```
+        pub fn stats(&self) -> $crate::RackStats {
+            self.storage().stats()
+        }
+
+        /// Call `f`, reporting every value stored on the rack and every slot
+        /// released in the meantime to the observer.
+        ///
+        /// This allows usage of the rack to be observed without polling. The
+        /// observer is only borrowed for the duration of the call, so it can keep
+        /// its state in local variables. Calls can be nested, an inner observer
+        /// replaces the outer one until it returns. Events caused by the observer
+        /// itself, e.g. by dropping a unit, are not reported to it. Since the rack
+        /// cannot be borrowed mutably during the call, values taken out of it by
+        /// [`remove`](#method.remove) or [`retain`](#method.retain) are never
+        /// reported.
+        ///
+        /// # Examples
+        ///
+        /// Track the peak number of stored values:
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        /// let mut used = 0;
+        /// let mut peak = 0;
+        ///
+        /// let track = |event| match event {
+        ///     RackEvent::Allocated(_) => {
+        ///         used += 1;
+        ///         peak = peak.max(used);
+        ///     }
+        ///     RackEvent::Freed(_) => used -= 1,
+        /// };
+        /// rack.observe(track, || {
+        ///     let a = rack.must_add(1);
+        ///     let b = rack.must_add(2);
+        ///     drop(a);
+        ///     drop(b);
+        ///     rack.must_add(3).leak();
+        /// });
+        ///
+        /// assert_eq!(peak, 2);
+        /// assert_eq!(used, 1);
+        /// ```
+        pub fn observe<R>(
+            &self,
+            observer: impl FnMut($crate::RackEvent),
+            f: impl FnOnce() -> R,
+        ) -> R {
+            self.storage().state.observer.observe(observer, f)
+        }
+
+        /// Rebuild a unit from a pointer obtained through
+        /// [`Unit::into_raw`](struct.Unit.html#method.into_raw).
+        ///
+        /// # Safety
+        ///
+        /// The pointer must have been returned by `into_raw` of a unit allocated
+        /// from this very rack. Only a single unit may be rebuilt from it,
+        /// otherwise the value would be owned and dropped twice.
+        ///
+        /// # Panics
+        ///
+        /// This method will panic in case the pointer does not refer to a slot of
+        /// this rack held by a unit.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use heapnotize::*;
+        #[doc = $rack]
+        ///
+        /// let ptr = rack.must_add(5).into_raw();
+        /// let five = unsafe { rack.unit_from_raw(ptr) };
+        ///
+        /// assert_eq!(*five, 5);
+        /// ```
+        pub unsafe fn unit_from_raw(&self, ptr: *mut T) -> $crate::Unit<'_, T> {
+            self.storage().unit_from_raw(ptr)
+        }
+    };
+}
+
+pub(crate) use storage_methods;
@@ -0,0 +1,358 @@
+//! A thread-safe variant of the rack, allowing values to be stored from
+//! multiple threads at once, and the flags tracking occupancy of its slots.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::AtomicBool;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::Ordering;
+
+use crate::AddUnitError;
+
+/// A thread-safe rack holding up to `N` values of a type `T`.
+///
+/// Unlike [`ArrayRack`](struct.ArrayRack.html), this rack can be shared
+/// between threads and even kept in a `static` item. Occupancy of each slot is
+/// tracked by an atomic flag, so values can be added and dropped from
+/// multiple threads concurrently. Values added to the rack are owned by
+/// [`SyncUnit`](struct.SyncUnit.html).
+///
+/// The flags are of the type `F`, which is an `AtomicBool` unless specified
+/// otherwise. The same rack guarded by critical sections instead is available
+/// as [`CsRack`](type.CsRack.html) with the `critical-section` feature.
+///
+/// Since it returns a different type of units, `SyncRack` does not implement
+/// the [`Rack`](trait.Rack.html) trait.
+///
+/// # Examples
+///
+/// Keep a pool of values in a `static` item:
+///
+/// ```
+/// # use heapnotize::*;
+/// static RACK: SyncRack<i32, 64> = SyncRack::new();
+///
+/// let five = RACK.must_add(5);
+/// assert_eq!(*five, 5);
+/// ```
+pub struct SyncRack<T, const N: usize, F = AtomicBool> {
+    // A slot is claimed by raising its flag and it is released by clearing it
+    // once the value was dropped.
+    occupied: [F; N],
+    data: [UnsafeCell<MaybeUninit<T>>; N],
+}
+
+// The rack only hands out access to each value to a single unit, the same way
+// a `Mutex` would. It can therefore be shared as long as values can be sent
+// between threads.
+unsafe impl<T: Send, const N: usize, F: SlotFlag> Sync for SyncRack<T, N, F> {}
+
+impl<T, const N: usize, F: SlotFlag> SyncRack<T, N, F> {
+    /// Initialize a new thread-safe Rack with a capacity of `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<i32, 64>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            occupied: [const { F::FREE }; N],
+            data: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+        }
+    }
+
+    /// Add a value to the `Rack` and return an error if it is full.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case the `Rack` is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<_, 64>::new();
+    /// let five = rack.add(5).unwrap();
+    /// ```
+    pub fn add(&self, value: T) -> Result<SyncUnit<'_, T, F>, AddUnitError> {
+        let index = match F::claim_first(&self.occupied) {
+            Some(index) => index,
+            None => return Err(AddUnitError::FullRack { capacity: N }),
+        };
+        let cell = &self.data[index];
+        // This is safe since the slot was just claimed and nobody else can
+        // access it until it is released.
+        unsafe {
+            (*cell.get()).write(value);
+        }
+        Ok(SyncUnit {
+            occupied: &self.occupied[index],
+            cell,
+            index,
+        })
+    }
+
+    /// Add a value to the `Rack` and panic if it is full.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in case the `Rack` is fully populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<_, 64>::new();
+    /// let five = rack.must_add(5);
+    /// ```
+    pub fn must_add(&self, value: T) -> SyncUnit<'_, T, F> {
+        self.add(value).expect("The rack is full")
+    }
+}
+
+impl<T, const N: usize, F: SlotFlag> Default for SyncRack<T, N, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A type serving as an owner of a value stored on the
+/// [`SyncRack`](struct.SyncRack.html).
+///
+/// It works the same way as [`Unit`](struct.Unit.html). Once the `SyncUnit`
+/// gets out of the scope, the value that it holds gets dropped and its slot is
+/// released for other threads to use.
+pub struct SyncUnit<'a, T, F: SlotFlag = AtomicBool> {
+    occupied: &'a F,
+    cell: &'a UnsafeCell<MaybeUninit<T>>,
+    index: usize,
+}
+
+// The unit is the only owner of the value, so it behaves like `&mut T`.
+unsafe impl<T: Send, F: SlotFlag> Send for SyncUnit<'_, T, F> {}
+unsafe impl<T: Sync, F: SlotFlag> Sync for SyncUnit<'_, T, F> {}
+
+impl<T, F: SlotFlag> SyncUnit<'_, T, F> {
+    /// Get a reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<_, 64>::new();
+    /// let five = rack.must_add(5);
+    /// assert_eq!(*five.get_ref(), 5);
+    /// ```
+    pub fn get_ref(&self) -> &T {
+        // This code is safe since the slot is populated on `add` and only this
+        // unit has access to it.
+        unsafe { (*self.cell.get()).assume_init_ref() }
+    }
+
+    /// Get a mutable reference to the data stored on the Rack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<_, 64>::new();
+    ///
+    /// let mut number = rack.must_add(5);
+    /// *number.get_mut() = 10;
+    ///
+    /// assert_eq!(*number.get_ref(), 10);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut T {
+        // This code is safe since the slot is populated on `add` and only this
+        // unit has access to it.
+        unsafe { (*self.cell.get()).assume_init_mut() }
+    }
+
+    /// Get the index of the `Rack` slot occupied by this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let rack = SyncRack::<_, 64>::new();
+    /// let five = rack.must_add(5);
+    /// assert_eq!(five.index(), 0);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// When the SyncUnit gets out of scope, it will drop the stored value and
+/// release its slot on the Rack.
+impl<T, F: SlotFlag> Drop for SyncUnit<'_, T, F> {
+    fn drop(&mut self) {
+        // The slot is released even if the destructor of the value panics,
+        // same as with a `Unit`.
+        struct Release<'u, 'a, T, F: SlotFlag>(&'u SyncUnit<'a, T, F>);
+
+        impl<T, F: SlotFlag> Drop for Release<'_, '_, T, F> {
+            fn drop(&mut self) {
+                self.0.occupied.release();
+            }
+        }
+
+        let _release = Release(self);
+        // This is safe since the unit was the only owner of the stored data.
+        // The value is not accessed again, even if its destructor panics.
+        unsafe {
+            ptr::drop_in_place((*self.cell.get()).as_mut_ptr());
+        }
+    }
+}
+
+impl<T, F: SlotFlag> Deref for SyncUnit<'_, T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get_ref()
+    }
+}
+
+impl<T, F: SlotFlag> DerefMut for SyncUnit<'_, T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.get_mut()
+    }
+}
+
+impl<T: fmt::Debug, F: SlotFlag> fmt::Debug for SyncUnit<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct(F::UNIT_NAME)
+            .field("value", self.get_ref())
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// A flag tracking whether a slot of a [`SyncRack`](struct.SyncRack.html)
+/// is occupied.
+///
+/// It is implemented by `AtomicBool` on targets supporting atomic
+/// compare-and-swap and by `critical_section::Mutex<Cell<bool>>` with the
+/// `critical-section` feature.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SlotFlag: Sized + Sync + private::Sealed {
+    #[doc(hidden)]
+    const FREE: Self;
+
+    // Name of the unit type printed by its `Debug` implementation.
+    #[doc(hidden)]
+    const UNIT_NAME: &'static str;
+
+    // Raise the first cleared flag and return its index.
+    #[doc(hidden)]
+    fn claim_first(flags: &[Self]) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn release(&self);
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+#[cfg(target_has_atomic = "8")]
+impl private::Sealed for AtomicBool {}
+
+// Each flag is claimed on its own, so threads looking for a free slot at the
+// same time never block each other.
+#[cfg(target_has_atomic = "8")]
+impl SlotFlag for AtomicBool {
+    const FREE: Self = AtomicBool::new(false);
+    const UNIT_NAME: &'static str = "SyncUnit";
+
+    fn claim_first(flags: &[Self]) -> Option<usize> {
+        flags.iter().position(|flag| {
+            flag.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        })
+    }
+
+    fn release(&self) {
+        self.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(all(test, target_has_atomic = "8"))]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn add_unit_to_sync_rack() {
+        let rack = SyncRack::<_, 2>::new();
+
+        let mut unit = rack.must_add(10);
+        *unit += 10;
+
+        assert_eq!(*unit, 20);
+    }
+
+    #[test]
+    fn rejects_over_the_limit_of_sync_rack() {
+        let rack = SyncRack::<_, 2>::new();
+
+        let _unit1 = rack.must_add(10);
+        let _unit2 = rack.must_add(20);
+
+        assert!(rack.add(30).is_err());
+    }
+
+    #[test]
+    fn release_sync_slot_even_if_value_panics_on_drop() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        struct PanicOnDrop;
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                panic!("PanicOnDrop dropped");
+            }
+        }
+
+        let rack = SyncRack::<_, 1>::new();
+
+        let unit = rack.must_add(Some(PanicOnDrop));
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(unit))).is_err());
+
+        let unit = rack.must_add(None);
+        assert_eq!(unit.index(), 0);
+    }
+
+    #[test]
+    fn share_sync_rack_between_threads() {
+        const THREADS: usize = 4;
+        const ITERATIONS: usize = 1000;
+
+        let rack = SyncRack::<_, THREADS>::new();
+
+        std::thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let rack = &rack;
+                scope.spawn(move || {
+                    for i in 0..ITERATIONS {
+                        let value = thread * ITERATIONS + i;
+                        let unit = rack.must_add(value);
+                        assert_eq!(*unit, value);
+                    }
+                });
+            }
+        });
+
+        // All the slots must have been released by the threads.
+        let _units: [SyncUnit<_>; THREADS] = core::array::from_fn(|i| rack.must_add(i));
+        assert!(rack.add(THREADS).is_err());
+    }
+}
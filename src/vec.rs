@@ -0,0 +1,172 @@
+//! Moving values built in [heapless](https://docs.rs/heapless) vectors onto
+//! racks, enabled by the `heapless` feature.
+
+use heapless::Vec;
+
+use crate::policy::SlotPolicy;
+use crate::{Align, AlignedRack, ArrayRack, RackSlice, SupportedAlign};
+
+impl<T, const N: usize, P: SlotPolicy> ArrayRack<T, N, P> {
+    /// Move values from the front of the vector to the rack, keeping them
+    /// owned by the rack, and return how many of them were placed.
+    ///
+    /// Values are moved in order until the rack fills up. Those which did not
+    /// fit stay in the vector. The moved values can be then reached through
+    /// [`iter`](struct.ArrayRack.html#method.iter) or
+    /// [`get_at`](struct.ArrayRack.html#method.get_at). Like all the values
+    /// owned by the rack, they are not dropped together with it, see
+    /// [keys](struct.ArrayRack.html#keys). Available with the `heapless`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heapnotize::*;
+    /// let mut values = heapless::Vec::<i32, 4>::new();
+    /// values.extend([1, 2, 3]);
+    ///
+    /// let rack = Rack2::new();
+    /// assert_eq!(rack.add_all_from(&mut values), 2);
+    ///
+    /// assert!(rack.iter().eq(&[1, 2]));
+    /// assert_eq!(values, [3]);
+    /// ```
+    pub fn add_all_from<const CAP: usize>(&self, values: &mut Vec<T, CAP>) -> usize {
+        move_while(
+            values,
+            || self.first_free().is_some(),
+            |value| self.insert(value).is_ok(),
+        )
+    }
+}
+
+impl<T> RackSlice<'_, T> {
+    /// Move values from the front of the vector to the rack and return how
+    /// many of them were placed.
+    ///
+    /// See [`ArrayRack::add_all_from`](struct.ArrayRack.html#method.add_all_from).
+    pub fn add_all_from<const CAP: usize>(&self, values: &mut Vec<T, CAP>) -> usize {
+        move_while(
+            values,
+            || self.first_free().is_some(),
+            |value| self.insert(value).is_ok(),
+        )
+    }
+}
+
+impl<T, const ALIGN: usize, const N: usize> AlignedRack<T, ALIGN, N>
+where
+    Align<ALIGN>: SupportedAlign,
+{
+    /// Move values from the front of the vector to the rack and return how
+    /// many of them were placed.
+    ///
+    /// See [`ArrayRack::add_all_from`](struct.ArrayRack.html#method.add_all_from).
+    pub fn add_all_from<const CAP: usize>(&self, values: &mut Vec<T, CAP>) -> usize {
+        move_while(
+            values,
+            || self.first_free().is_some(),
+            |value| self.insert(value).is_ok(),
+        )
+    }
+}
+
+// Move values from the front of the vector while there is room for them. A
+// value is taken out of the vector only once there is a slot to store it in,
+// moving stops should the rack refuse it anyway. The vector is reversed, so
+// values are popped from its end instead of shifting the rest of them after
+// each one, and reversed back afterwards, even if storing a value panics.
+fn move_while<T, const CAP: usize>(
+    values: &mut Vec<T, CAP>,
+    has_room: impl Fn() -> bool,
+    insert: impl Fn(T) -> bool,
+) -> usize {
+    struct Reversed<'a, T, const CAP: usize>(&'a mut Vec<T, CAP>);
+
+    impl<T, const CAP: usize> Drop for Reversed<'_, T, CAP> {
+        fn drop(&mut self) {
+            self.0.reverse();
+        }
+    }
+
+    let mut placed = 0;
+    values.reverse();
+    let values = Reversed(values);
+    while !values.0.is_empty() && has_room() {
+        let value = values.0.pop().expect("the vector is not empty");
+        if !insert(value) {
+            break;
+        }
+        placed += 1;
+    }
+    placed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rack, Rack4, Slot};
+
+    #[test]
+    fn move_all_values_from_vector_to_rack() {
+        let mut values = Vec::<_, 3>::new();
+        values.extend([1, 2, 3]);
+
+        let rack = Rack4::new();
+        assert_eq!(rack.add_all_from(&mut values), 3);
+
+        assert!(values.is_empty());
+        assert!(rack.iter().eq(&[1, 2, 3]));
+        assert_eq!(rack.first_free(), Some(3));
+    }
+
+    #[test]
+    fn leave_values_which_do_not_fit_in_vector() {
+        let mut values = Vec::<_, 4>::new();
+        values.extend([1, 2, 3, 4]);
+
+        let rack = Rack4::new();
+        let _unit = rack.must_add(0);
+        let _key = rack.must_insert(0);
+        assert_eq!(rack.add_all_from(&mut values), 2);
+
+        assert_eq!(values, [3, 4]);
+        assert!(rack.iter().eq(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn keep_order_of_values_left_in_vector_when_storing_panics() {
+        extern crate std;
+
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut values = Vec::<_, 4>::new();
+        values.extend([1, 2, 3, 4]);
+
+        let rack = Rack4::new();
+        let mut allocated = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            rack.observe(
+                |_| {
+                    allocated += 1;
+                    assert!(allocated < 2, "the second value is refused");
+                },
+                || rack.add_all_from(&mut values),
+            )
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(values, [3, 4]);
+    }
+
+    #[test]
+    fn move_values_to_rack_slice() {
+        let mut values = Vec::<_, 2>::new();
+        values.extend([1, 2]);
+
+        let mut buffer = [const { Slot::new() }; 4];
+        let rack = RackSlice::new(&mut buffer);
+        assert_eq!(rack.add_all_from(&mut values), 2);
+        assert!(rack.iter().eq(&[1, 2]));
+    }
+}